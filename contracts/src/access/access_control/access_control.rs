@@ -36,6 +36,7 @@ use openbrush::{
         AccountId,
         DefaultEnv,
         Storage,
+        Timestamp,
     },
 };
 
@@ -44,8 +45,11 @@ use openbrush::{
 pub struct Data {
     pub admin_roles: Mapping<RoleType, RoleType, ValueGuard<RoleType>>,
     pub members: Mapping<(RoleType, Option<AccountId>), (), MembersKey>,
+    pub role_expiries: Mapping<RoleMember, Timestamp, MembersKey>,
 }
 
+pub type RoleMember = (RoleType, Option<AccountId>);
+
 pub struct MembersKey;
 
 impl<'a> TypeGuard<'a> for MembersKey {
@@ -55,6 +59,7 @@ impl<'a> TypeGuard<'a> for MembersKey {
 pub const DEFAULT_ADMIN_ROLE: RoleType = 0;
 
 /// Modifier that checks that `caller` has a specific role.
+/// Returns `RoleExpired` error if the role of `caller` has expired.
 #[modifier_definition]
 pub fn only_role<T, F, R, E>(instance: &mut T, body: F, role: RoleType) -> Result<R, E>
 where
@@ -87,9 +92,36 @@ pub trait AccessControlImpl: Internal + MembersManager + Sized {
         Ok(())
     }
 
+    #[modifiers(only_role(self.get_role_admin(role)))]
+    fn grant_role_with_expiry(
+        &mut self,
+        role: RoleType,
+        account: Option<AccountId>,
+        expiry: Timestamp,
+    ) -> Result<(), AccessControlError> {
+        if self._has_role(role, &account) {
+            return Err(AccessControlError::RoleRedundant)
+        }
+        if expiry <= Self::env().block_timestamp() {
+            return Err(AccessControlError::RoleExpired)
+        }
+        self._add(role, &account);
+        self._set_role_expiry(role, &account, Some(expiry));
+        self._emit_role_granted(role, account, Some(Self::env().caller()));
+        Ok(())
+    }
+
+    fn role_expiry(&self, role: RoleType, account: Option<AccountId>) -> Option<Timestamp> {
+        self._get_role_expiry(role, &account)
+    }
+
     #[modifiers(only_role(self.get_role_admin(role)))]
     fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
-        self._check_role(role, account)?;
+        // The expired role can be revoked to clean up the storage
+        match self._check_role(role, account) {
+            Ok(()) | Err(AccessControlError::RoleExpired) => {}
+            Err(err) => return Err(err),
+        }
         self._do_revoke_role(role, account);
         Ok(())
     }
@@ -98,7 +130,10 @@ pub trait AccessControlImpl: Internal + MembersManager + Sized {
         if account != Some(Self::env().caller()) {
             return Err(AccessControlError::InvalidCaller)
         }
-        self._check_role(role, account)?;
+        match self._check_role(role, account) {
+            Ok(()) | Err(AccessControlError::RoleExpired) => {}
+            Err(err) => return Err(err),
+        }
         self._do_revoke_role(role, account);
         Ok(())
     }
@@ -114,19 +149,31 @@ pub trait MembersManager {
     fn _get_role_admin(&self, role: RoleType) -> Option<RoleType>;
 
     fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType);
+
+    fn _get_role_expiry(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp>;
+
+    fn _set_role_expiry(&mut self, role: RoleType, member: &Option<AccountId>, expiry: Option<Timestamp>);
 }
 
 pub trait MembersManagerImpl: Storage<Data> {
     fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
-        self.data().members.contains(&(role, address))
+        if !self.data().members.contains(&(role, address)) {
+            return false
+        }
+        match self.data().role_expiries.get(&(role, address)) {
+            Some(expiry) => Self::env().block_timestamp() < expiry,
+            None => true,
+        }
     }
 
     fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().members.insert(&(role, member), &());
+        self.data().role_expiries.remove(&(role, member));
     }
 
     fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().members.remove(&(role, member));
+        self.data().role_expiries.remove(&(role, member));
     }
 
     fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
@@ -136,6 +183,17 @@ pub trait MembersManagerImpl: Storage<Data> {
     fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
         self.data().admin_roles.insert(role, &new_admin);
     }
+
+    fn _get_role_expiry(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+        self.data().role_expiries.get(&(role, member))
+    }
+
+    fn _set_role_expiry(&mut self, role: RoleType, member: &Option<AccountId>, expiry: Option<Timestamp>) {
+        match expiry {
+            Some(expiry) => self.data().role_expiries.insert(&(role, member), &expiry),
+            None => self.data().role_expiries.remove(&(role, member)),
+        }
+    }
 }
 
 pub trait Internal {
@@ -203,6 +261,9 @@ pub trait InternalImpl: Internal + MembersManager + Sized {
 
     fn _check_role(&self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
         if !self._has_role(role, &account) {
+            if self._get_role_expiry(role, &account).is_some() {
+                return Err(AccessControlError::RoleExpired)
+            }
             return Err(AccessControlError::MissingRole)
         }
         Ok(())
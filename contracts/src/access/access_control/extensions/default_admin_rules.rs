@@ -0,0 +1,226 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    access_control,
+    access_control::extensions::default_admin_rules,
+    traits::access_control::{
+        extensions::default_admin_rules::*,
+        *,
+    },
+};
+pub use access_control::{
+    AccessControlImpl,
+    Internal as _,
+    InternalImpl as _,
+    MembersManager,
+    DEFAULT_ADMIN_ROLE,
+};
+pub use default_admin_rules::Internal as _;
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub current_default_admin: Option<AccountId>,
+    #[lazy]
+    pub pending_default_admin: Option<AccountId>,
+    #[lazy]
+    pub pending_default_admin_schedule: Timestamp,
+    #[lazy]
+    pub default_admin_delay: Timestamp,
+}
+
+pub trait AccessControlDefaultAdminRulesImpl:
+    Storage<Data> + access_control::Internal + MembersManager + AccessControlImpl + Internal + Sized
+{
+    fn default_admin(&self) -> Option<AccountId> {
+        self.data().current_default_admin.get_or_default()
+    }
+
+    fn pending_default_admin(&self) -> (Option<AccountId>, Timestamp) {
+        (
+            self.data().pending_default_admin.get_or_default(),
+            self.data().pending_default_admin_schedule.get_or_default(),
+        )
+    }
+
+    fn default_admin_delay(&self) -> Timestamp {
+        self.data().default_admin_delay.get_or_default()
+    }
+
+    #[modifiers(access_control::only_role(<Self as access_control::Internal>::_default_admin()))]
+    fn begin_default_admin_transfer(&mut self, new_admin: Option<AccountId>) -> Result<(), AccessControlError> {
+        let schedule = Self::env()
+            .block_timestamp()
+            .saturating_add(self.data().default_admin_delay.get_or_default());
+
+        self.data().pending_default_admin.set(&new_admin);
+        self.data().pending_default_admin_schedule.set(&schedule);
+        Internal::_emit_default_admin_transfer_scheduled(self, new_admin, schedule);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(<Self as access_control::Internal>::_default_admin()))]
+    fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+        self.data().pending_default_admin.set(&None);
+        self.data().pending_default_admin_schedule.set(&0);
+        Internal::_emit_default_admin_transfer_canceled(self);
+        Ok(())
+    }
+
+    fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+        let caller = Some(Self::env().caller());
+        let (pending_admin, schedule) = AccessControlDefaultAdminRulesImpl::pending_default_admin(self);
+        if pending_admin != caller {
+            return Err(AccessControlError::InvalidCaller)
+        }
+        if schedule == 0 || Self::env().block_timestamp() < schedule {
+            return Err(AccessControlError::DefaultAdminTransferNotReady)
+        }
+
+        let role = <Self as access_control::Internal>::_default_admin();
+        let old_admin = self.data().current_default_admin.get_or_default();
+        self.data().pending_default_admin.set(&None);
+        self.data().pending_default_admin_schedule.set(&0);
+        if old_admin.is_some() {
+            access_control::Internal::_do_revoke_role(self, role, old_admin);
+        }
+        access_control::Internal::_setup_role(self, role, caller);
+        Ok(())
+    }
+
+    #[modifiers(access_control::only_role(<Self as access_control::Internal>::_default_admin()))]
+    fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError> {
+        let old_delay = self.data().default_admin_delay.get_or_default();
+        self.data().default_admin_delay.set(&new_delay);
+        Internal::_emit_default_admin_delay_changed(self, old_delay, new_delay);
+        Ok(())
+    }
+
+    /// Replaces `AccessControl::grant_role`, the default admin role can't be granted directly.
+    fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            return Err(AccessControlError::EnforcedDefaultAdminRules)
+        }
+        AccessControlImpl::grant_role(self, role, account)
+    }
+
+    /// Replaces `AccessControl::grant_role_with_expiry`, the default admin role can't be granted directly.
+    fn grant_role_with_expiry(
+        &mut self,
+        role: RoleType,
+        account: Option<AccountId>,
+        expiry: Timestamp,
+    ) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            return Err(AccessControlError::EnforcedDefaultAdminRules)
+        }
+        AccessControlImpl::grant_role_with_expiry(self, role, account, expiry)
+    }
+
+    /// Replaces `AccessControl::revoke_role`, the default admin role can't be revoked directly.
+    fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            return Err(AccessControlError::EnforcedDefaultAdminRules)
+        }
+        AccessControlImpl::revoke_role(self, role, account)
+    }
+
+    /// Replaces `AccessControl::renounce_role`, the default admin can renounce the role only
+    /// after the transfer to `None` was scheduled and the delay has passed.
+    fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            if account != Some(Self::env().caller()) {
+                return Err(AccessControlError::InvalidCaller)
+            }
+
+            let (pending_admin, schedule) = AccessControlDefaultAdminRulesImpl::pending_default_admin(self);
+            if pending_admin.is_some() || schedule == 0 || Self::env().block_timestamp() < schedule {
+                return Err(AccessControlError::DefaultAdminTransferNotReady)
+            }
+            self.data().pending_default_admin_schedule.set(&0);
+        }
+        AccessControlImpl::renounce_role(self, role, account)
+    }
+}
+
+pub trait Internal {
+    /// The user must override those methods using their event definition.
+    fn _emit_default_admin_transfer_scheduled(&mut self, new_admin: Option<AccountId>, accept_schedule: Timestamp);
+
+    fn _emit_default_admin_transfer_canceled(&mut self);
+
+    fn _emit_default_admin_delay_changed(&mut self, old_delay: Timestamp, new_delay: Timestamp);
+
+    fn _init_with_default_admin(&mut self, admin: Option<AccountId>, delay: Timestamp);
+}
+
+pub trait InternalImpl: Storage<Data> + access_control::Internal + MembersManager + Internal + Sized {
+    fn _emit_default_admin_transfer_scheduled(&mut self, _new_admin: Option<AccountId>, _accept_schedule: Timestamp) {}
+
+    fn _emit_default_admin_transfer_canceled(&mut self) {}
+
+    fn _emit_default_admin_delay_changed(&mut self, _old_delay: Timestamp, _new_delay: Timestamp) {}
+
+    fn _init_with_default_admin(&mut self, admin: Option<AccountId>, delay: Timestamp) {
+        self.data().default_admin_delay.set(&delay);
+        access_control::Internal::_init_with_admin(self, admin);
+    }
+
+    /// Replaces `access_control::Internal::_setup_role`, the default admin role is granted
+    /// only if there is no default admin yet.
+    fn _setup_role(&mut self, role: RoleType, member: Option<AccountId>) {
+        if role == <Self as access_control::Internal>::_default_admin() {
+            if self.data().current_default_admin.get_or_default().is_some() {
+                return
+            }
+            self.data().current_default_admin.set(&member);
+        }
+
+        if !self._has_role(role, &member) {
+            self._add(role, &member);
+
+            access_control::Internal::_emit_role_granted(self, role, member, None);
+        }
+    }
+
+    /// Replaces `access_control::Internal::_do_revoke_role`, clears the default admin
+    /// if it loses the default admin role.
+    fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>) {
+        if role == <Self as access_control::Internal>::_default_admin()
+            && self.data().current_default_admin.get_or_default() == account
+        {
+            self.data().current_default_admin.set(&None);
+        }
+
+        self._remove(role, &account);
+        access_control::Internal::_emit_role_revoked(self, role, account, Self::env().caller());
+    }
+}
@@ -31,6 +31,8 @@ pub use access_control::{
     AccessControlImpl,
     Internal as _,
     InternalImpl as _,
+    MembersKey,
+    RoleMember,
 };
 use openbrush::{
     storage::{
@@ -41,6 +43,7 @@ use openbrush::{
     traits::{
         AccountId,
         Storage,
+        Timestamp,
     },
 };
 
@@ -49,19 +52,28 @@ use openbrush::{
 pub struct Data {
     pub admin_roles: Mapping<RoleType, RoleType, ValueGuard<RoleType>>,
     pub role_members: MultiMapping<RoleType, Option<AccountId>, ValueGuard<RoleType>>,
+    pub role_expiries: Mapping<RoleMember, Timestamp, MembersKey>,
 }
 
 pub trait MembersManagerImpl: Storage<Data> {
     fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
-        self.data().role_members.contains_value(role, address)
+        if !self.data().role_members.contains_value(role, address) {
+            return false
+        }
+        match self.data().role_expiries.get(&(role, address)) {
+            Some(expiry) => Self::env().block_timestamp() < expiry,
+            None => true,
+        }
     }
 
     fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().role_members.insert(role, member);
+        self.data().role_expiries.remove(&(role, member));
     }
 
     fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
         self.data().role_members.remove_value(role, member);
+        self.data().role_expiries.remove(&(role, member));
     }
 
     fn _get_role_admin(&self, role: RoleType) -> Option<RoleType> {
@@ -71,6 +83,17 @@ pub trait MembersManagerImpl: Storage<Data> {
     fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
         self.data().admin_roles.insert(role, &new_admin);
     }
+
+    fn _get_role_expiry(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+        self.data().role_expiries.get(&(role, member))
+    }
+
+    fn _set_role_expiry(&mut self, role: RoleType, member: &Option<AccountId>, expiry: Option<Timestamp>) {
+        match expiry {
+            Some(expiry) => self.data().role_expiries.insert(&(role, member), &expiry),
+            None => self.data().role_expiries.remove(&(role, member)),
+        }
+    }
 }
 
 pub trait AccessControlEnumerableImpl: Storage<Data> {
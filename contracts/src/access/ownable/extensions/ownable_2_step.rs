@@ -0,0 +1,100 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    ownable,
+    ownable::extensions::ownable_2_step,
+    traits::ownable::{
+        ownable_2_step::*,
+        *,
+    },
+};
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+pub use ownable::{
+    Internal as _,
+    InternalImpl as _,
+    OwnableImpl,
+};
+pub use ownable_2_step::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub pending_owner: Option<AccountId>,
+}
+
+pub trait Ownable2StepImpl: Storage<ownable::Data> + Storage<Data> + ownable::Internal + Internal {
+    fn pending_owner(&self) -> Option<AccountId> {
+        self.data::<Data>().pending_owner.get_or_default()
+    }
+
+    fn accept_ownership(&mut self) -> Result<(), OwnableError> {
+        let caller = Self::env().caller();
+        if self.data::<Data>().pending_owner.get_or_default() != Some(caller) {
+            return Err(OwnableError::CallerIsNotPendingOwner)
+        }
+
+        let old_owner = self.data::<ownable::Data>().owner.get_or_default();
+        self.data::<Data>().pending_owner.set(&None);
+        self.data::<ownable::Data>().owner.set(&Some(caller));
+        ownable::Internal::_emit_ownership_transferred_event(self, old_owner, Some(caller));
+        Ok(())
+    }
+
+    /// Replaces `Ownable::renounce_ownership`, also cancels the pending transfer.
+    #[modifiers(ownable::only_owner)]
+    fn renounce_ownership(&mut self) -> Result<(), OwnableError> {
+        let old_owner = self.data::<ownable::Data>().owner.get_or_default();
+        self.data::<Data>().pending_owner.set(&None);
+        self.data::<ownable::Data>().owner.set(&None);
+        ownable::Internal::_emit_ownership_transferred_event(self, old_owner, None);
+        Ok(())
+    }
+
+    /// Replaces `Ownable::transfer_ownership`, the `new_owner` becomes the pending owner.
+    #[modifiers(ownable::only_owner)]
+    fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+        if new_owner == AccountId::from([0x0; 32]) {
+            return Err(OwnableError::NewOwnerIsZero)
+        }
+
+        let owner = self.data::<ownable::Data>().owner.get_or_default();
+        self.data::<Data>().pending_owner.set(&Some(new_owner));
+        Internal::_emit_ownership_transfer_started_event(self, owner, new_owner);
+        Ok(())
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_ownership_transfer_started_event(&self, _previous: Option<AccountId>, _new: AccountId);
+}
+
+pub trait InternalImpl: Storage<Data> + Internal {
+    fn _emit_ownership_transfer_started_event(&self, _previous: Option<AccountId>, _new: AccountId) {}
+}
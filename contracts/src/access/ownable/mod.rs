@@ -33,6 +33,10 @@ use openbrush::{
 };
 pub use ownable::Internal as _;
 
+pub mod extensions {
+    pub mod ownable_2_step;
+}
+
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
@@ -69,6 +73,10 @@ pub trait OwnableImpl: Storage<Data> + Internal {
 
     #[modifiers(only_owner)]
     fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+        if new_owner == AccountId::from([0x0; 32]) {
+            return Err(OwnableError::NewOwnerIsZero)
+        }
+
         let old_owner = self.data().owner.get_or_default();
         self.data().owner.set(&Some(new_owner));
         self._emit_ownership_transferred_event(old_owner, Some(new_owner));
@@ -0,0 +1,90 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PaymentSplitter`] that allows the owner to manage the payees
+pub use crate::{
+    ownable,
+    payment_splitter,
+    payment_splitter::extensions::manageable,
+    traits::payment_splitter::{
+        manageable::*,
+        *,
+    },
+};
+use openbrush::{
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+pub use payment_splitter::{
+    Internal as _,
+    InternalImpl as _,
+    PaymentSplitterImpl,
+};
+
+pub trait PaymentSplitterManageableImpl:
+    Storage<payment_splitter::Data> + Storage<ownable::Data> + payment_splitter::Internal
+{
+    #[modifiers(ownable::only_owner)]
+    fn add_payee(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError> {
+        payment_splitter::Internal::_settle_all(self)?;
+        payment_splitter::Internal::_add_payee(self, payee, shares)?;
+        payment_splitter::Internal::_rebase_released(self)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    fn remove_payee(&mut self, payee: AccountId) -> Result<(), PaymentSplitterError> {
+        payment_splitter::Internal::_settle_all(self)?;
+        payment_splitter::Internal::_remove_payee(self, payee)?;
+        payment_splitter::Internal::_rebase_released(self)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    fn update_shares(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError> {
+        payment_splitter::Internal::_settle_all(self)?;
+        payment_splitter::Internal::_update_shares(self, payee, shares)?;
+        payment_splitter::Internal::_rebase_released(self)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    fn add_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+        payment_splitter::Internal::_add_token(self, token)
+    }
+
+    #[modifiers(ownable::only_owner)]
+    fn remove_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+        payment_splitter::Internal::_remove_token(self, token)
+    }
+
+    fn owed(&self, account: AccountId) -> Balance {
+        self.data::<payment_splitter::Data>().owed.get(&account).unwrap_or(0)
+    }
+
+    fn owed_token(&self, token: AccountId, account: AccountId) -> Balance {
+        self.data::<payment_splitter::Data>()
+            .owed_tokens
+            .get(&(&token, &account))
+            .unwrap_or(0)
+    }
+}
@@ -19,21 +19,32 @@
 // OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
+use crate::traits::psp22::PSP22Ref;
 pub use crate::{
     payment_splitter,
     traits::payment_splitter::*,
 };
+use core::cmp::min;
 use ink::prelude::vec::Vec;
 use openbrush::{
-    storage::Mapping,
+    storage::{
+        Mapping,
+        StorageVec,
+        TypeGuard,
+    },
     traits::{
         AccountId,
         Balance,
         Storage,
+        String,
     },
 };
 pub use payment_splitter::Internal as _;
 
+pub mod extensions {
+    pub mod manageable;
+}
+
 #[derive(Default, Debug)]
 #[openbrush::storage_item]
 pub struct Data {
@@ -43,8 +54,60 @@ pub struct Data {
     pub total_released: Balance,
     pub shares: Mapping<AccountId, Balance>,
     pub released: Mapping<AccountId, Balance>,
+    /// Before the `StorageVec`, the payees were stored as a lazy `Vec` under the same storage key.
+    /// Upgradeable contracts deployed with that version must call `Internal::_migrate_payees`
+    /// once after `set_code_hash`, otherwise they read no payees.
+    pub payees: StorageVec<AccountId>,
+    pub total_released_tokens: Mapping<AccountId, Balance>,
+    pub released_tokens: Mapping<(AccountId, AccountId), Balance, ReleasedTokensKey>,
+    pub tokens: StorageVec<AccountId>,
     #[lazy]
-    pub payees: Vec<AccountId>,
+    pub total_owed: Balance,
+    pub owed: Mapping<AccountId, Balance>,
+    pub total_owed_tokens: Mapping<AccountId, Balance>,
+    pub owed_tokens: Mapping<(AccountId, AccountId), Balance, ReleasedTokensKey>,
+}
+
+pub struct ReleasedTokensKey;
+
+impl<'a> TypeGuard<'a> for ReleasedTokensKey {
+    type Type = &'a (&'a AccountId, &'a AccountId);
+}
+
+/// The maximum number of payees returned by one call of `PaymentSplitter::payees`.
+pub const MAX_PAGE_SIZE: u128 = 100;
+
+/// The maximum number of payees, it bounds the cost of the settlement of the payments.
+pub const MAX_PAYEES: u128 = 100;
+
+/// The maximum number of tracked PSP22 tokens, each of them is queried during the settlement of the payments.
+pub const MAX_TOKENS: u128 = 20;
+
+/// `value * shares / total_shares` rounded up, `None` on overflow.
+fn mul_div_ceil(value: Balance, shares: Balance, total_shares: Balance) -> Option<Balance> {
+    value
+        .checked_mul(shares)?
+        .checked_add(total_shares - 1)
+        .map(|product| product / total_shares)
+}
+
+/// Sets the released amounts of `token` of each of the `payees` to their part of the total released amount of `token`.
+fn rebase_released_token<T: Storage<Data>>(
+    instance: &mut T,
+    token: &AccountId,
+    payees: &[AccountId],
+) -> Result<(), PaymentSplitterError> {
+    let total_shares = instance.data().total_shares.get_or_default();
+    let total_released = instance.data().total_released_tokens.get(token).unwrap_or_default();
+
+    // Rounding up, so the sum of payments never exceeds the balance of the contract.
+    for account in payees {
+        let shares = instance.data().shares.get(account).unwrap_or_default();
+        let released = mul_div_ceil(total_released, shares, total_shares)
+            .ok_or_else(|| PaymentSplitterError::Custom(String::from("Released amount overflow")))?;
+        instance.data().released_tokens.insert(&(token, account), &released);
+    }
+    Ok(())
 }
 
 pub trait PaymentSplitterImpl: Storage<Data> + Internal {
@@ -65,7 +128,11 @@ pub trait PaymentSplitterImpl: Storage<Data> + Internal {
     }
 
     fn payee(&self, index: u32) -> Option<AccountId> {
-        self.data().payees.get_or_default().get(index as usize).cloned()
+        self.data().payees.get(index as u128)
+    }
+
+    fn payees(&self, start: u128, limit: u128) -> Vec<AccountId> {
+        self.data().payees.iter_range(start, min(limit, MAX_PAGE_SIZE))
     }
 
     fn receive(&mut self) {
@@ -75,6 +142,18 @@ pub trait PaymentSplitterImpl: Storage<Data> + Internal {
     fn release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError> {
         self._release(account)
     }
+
+    fn total_released_token(&self, token: AccountId) -> Balance {
+        self.data().total_released_tokens.get(&token).unwrap_or(0)
+    }
+
+    fn released_token(&self, token: AccountId, account: AccountId) -> Balance {
+        self.data().released_tokens.get(&(&token, &account)).unwrap_or(0)
+    }
+
+    fn release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError> {
+        self._release_token(token, account)
+    }
 }
 
 pub trait Internal {
@@ -85,6 +164,12 @@ pub trait Internal {
 
     fn _emit_payment_released_event(&self, to: AccountId, amount: Balance);
 
+    fn _emit_psp22_payment_released_event(&self, token: AccountId, to: AccountId, amount: Balance);
+
+    fn _emit_payee_removed_event(&self, account: AccountId);
+
+    fn _emit_shares_updated_event(&self, account: AccountId, shares: Balance);
+
     /// Inits an instance of `PaymentSplitter` where each account in `payees` is assigned the number of shares at
     /// the matching position in the `shares` array.
     ///
@@ -96,10 +181,64 @@ pub trait Internal {
 
     fn _add_payee(&mut self, payee: AccountId, share: Balance) -> Result<(), PaymentSplitterError>;
 
+    /// Removes the `payee` with its shares, the last payee can't be removed.
+    ///
+    /// Emits `PayeeRemoved`.
+    fn _remove_payee(&mut self, payee: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Sets the amount of `shares` held by the `payee`.
+    ///
+    /// Emits `SharesUpdated`.
+    fn _update_shares(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError>;
+
     /// Calls the `release` method for each `AccountId` in the `payees` vec.
     fn _release_all(&mut self) -> Result<(), PaymentSplitterError>;
 
+    /// Moves the payees stored as a lazy `Vec` by the previous versions of `Data` to the `StorageVec`.
+    /// Does nothing if there are no payees in the previous format.
+    fn _migrate_payees(&mut self) -> Result<(), PaymentSplitterError>;
+
+    /// Adds the PSP22 `token` to the tracked tokens. Only the tracked tokens are released by `_release_token`
+    /// and settled by `_settle_all`. Does nothing if the `token` is already tracked.
+    ///
+    /// If the `token` was tracked before, its released amounts are rebased to the current shares.
+    fn _add_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Removes the PSP22 `token` from the tracked tokens, for example if its contract traps.
+    /// The amounts of the `token` credited to the payees before can still be released.
+    fn _remove_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Credits the pending native token payment of `account` to the amount owed to it and returns the credited amount.
+    /// `current_balance` is the balance of the contract available for the payments.
+    fn _settle(&mut self, account: AccountId, current_balance: Balance) -> Balance;
+
+    /// Credits the pending payment of PSP22 `token` to `account`, like `_settle`.
+    fn _settle_token(&mut self, token: AccountId, account: AccountId, current_balance: Balance) -> Balance;
+
+    /// Credits the pending payments of each payee in native token and in every tracked PSP22 token.
+    /// Nothing is transferred, so the settlement can't fail because of a payee.
+    ///
+    /// Fails if the balance of a tracked token can't be queried, such a token can be removed with `_remove_token`.
+    fn _settle_all(&mut self) -> Result<(), PaymentSplitterError>;
+
+    /// Sets the released amounts of each payee to their part of the total released amounts under the current shares.
+    /// After the shares are changed, it keeps payments proportional to the new shares.
+    ///
+    /// Should be called after `_settle_all` and the change of the shares.
+    fn _rebase_released(&mut self) -> Result<(), PaymentSplitterError>;
+
     fn _release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Returns the balance of PSP22 `token` held by the contract.
+    fn _token_balance(&self, token: AccountId) -> Result<Balance, PaymentSplitterError>;
+
+    /// Transfers `amount` of PSP22 `token` from the contract to `to`.
+    fn _transfer_token(&mut self, token: AccountId, to: AccountId, amount: Balance)
+        -> Result<(), PaymentSplitterError>;
+
+    /// Transfers to `account` the amount of PSP22 `token` they are owed.
+    /// The `token` must be tracked, unless `account` was credited with it before the `token` was removed.
+    fn _release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError>;
 }
 
 pub trait InternalImpl: Storage<Data> + Internal {
@@ -109,6 +248,12 @@ pub trait InternalImpl: Storage<Data> + Internal {
 
     fn _emit_payment_released_event(&self, _to: AccountId, _amount: Balance) {}
 
+    fn _emit_psp22_payment_released_event(&self, _token: AccountId, _to: AccountId, _amount: Balance) {}
+
+    fn _emit_payee_removed_event(&self, _account: AccountId) {}
+
+    fn _emit_shares_updated_event(&self, _account: AccountId, _shares: Balance) {}
+
     fn _init(&mut self, payees_and_shares: Vec<(AccountId, Balance)>) -> Result<(), PaymentSplitterError> {
         if payees_and_shares.is_empty() {
             return Err(PaymentSplitterError::NoPayees)
@@ -127,10 +272,11 @@ pub trait InternalImpl: Storage<Data> + Internal {
         if self.data().shares.get(&payee).is_some() {
             return Err(PaymentSplitterError::AlreadyHasShares)
         }
+        if self.data().payees.len() >= MAX_PAYEES {
+            return Err(PaymentSplitterError::TooManyPayees)
+        }
 
-        let mut payees = self.data().payees.get_or_default();
-        payees.push(payee);
-        self.data().payees.set(&payees);
+        self.data().payees.push(&payee);
 
         self.data().shares.insert(&payee, &share);
 
@@ -141,37 +287,212 @@ pub trait InternalImpl: Storage<Data> + Internal {
         Ok(())
     }
 
+    fn _remove_payee(&mut self, payee: AccountId) -> Result<(), PaymentSplitterError> {
+        let share = self
+            .data()
+            .shares
+            .get(&payee)
+            .ok_or(PaymentSplitterError::AccountHasNoShares)?;
+        let len = self.data().payees.len();
+        if len == 1 {
+            return Err(PaymentSplitterError::NoPayees)
+        }
+
+        let index = (0..len)
+            .find(|index| self.data().payees.get(*index) == Some(payee))
+            .ok_or(PaymentSplitterError::AccountHasNoShares)?;
+        self.data().payees.swap_remove(index);
+
+        self.data().shares.remove(&payee);
+
+        let new_shares = self.data().total_shares.get_or_default() - share;
+        self.data().total_shares.set(&new_shares);
+
+        Internal::_emit_payee_removed_event(self, payee);
+        Ok(())
+    }
+
+    fn _update_shares(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError> {
+        if shares == 0 {
+            return Err(PaymentSplitterError::SharesAreZero)
+        }
+        let old_shares = self
+            .data()
+            .shares
+            .get(&payee)
+            .ok_or(PaymentSplitterError::AccountHasNoShares)?;
+
+        self.data().shares.insert(&payee, &shares);
+
+        let new_shares = self.data().total_shares.get_or_default() - old_shares + shares;
+        self.data().total_shares.set(&new_shares);
+
+        Internal::_emit_shares_updated_event(self, payee, shares);
+        Ok(())
+    }
+
     fn _release_all(&mut self) -> Result<(), PaymentSplitterError> {
-        let payees = self.data().payees.get_or_default();
-        let len = payees.len();
+        let len = self.data().payees.len();
+        let payees = self.data().payees.iter_range(0, len);
 
-        for account in payees.iter().take(len) {
-            Internal::_release(self, *account)?;
+        for account in payees {
+            Internal::_release(self, account)?;
         }
 
         Ok(())
     }
 
-    fn _release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError> {
-        if self.data().shares.get(&account).is_none() {
-            return Err(PaymentSplitterError::AccountHasNoShares)
+    fn _migrate_payees(&mut self) -> Result<(), PaymentSplitterError> {
+        // The lazy `Vec` was stored directly under the key of the field
+        let old_payees = ink::env::get_contract_storage::<u32, Vec<AccountId>>(&STORAGE_KEY_DATA_PAYEES)
+            .map_err(|_| PaymentSplitterError::Custom(String::from("Failed to decode the old payees")))?;
+
+        if let Some(old_payees) = old_payees {
+            for payee in old_payees.iter() {
+                self.data().payees.push(payee);
+            }
+            ink::env::clear_contract_storage(&STORAGE_KEY_DATA_PAYEES);
+        }
+        Ok(())
+    }
+
+    fn _add_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+        let len = self.data().tokens.len();
+        if self.data().tokens.iter_range(0, len).contains(&token) {
+            return Ok(())
+        }
+        if len >= MAX_TOKENS {
+            return Err(PaymentSplitterError::TooManyTokens)
+        }
+
+        self.data().tokens.push(&token);
+
+        if self.data().total_released_tokens.get(&token).unwrap_or_default() > 0 {
+            let payees_len = self.data().payees.len();
+            let payees = self.data().payees.iter_range(0, payees_len);
+            rebase_released_token(self, &token, &payees)?;
+        }
+        Ok(())
+    }
+
+    fn _remove_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+        let len = self.data().tokens.len();
+        let index = (0..len)
+            .find(|index| self.data().tokens.get(*index) == Some(token))
+            .ok_or(PaymentSplitterError::TokenIsNotTracked)?;
+        self.data().tokens.swap_remove(index);
+        Ok(())
+    }
+
+    fn _settle(&mut self, account: AccountId, current_balance: Balance) -> Balance {
+        let shares = self.data().shares.get(&account).unwrap_or_default();
+        if shares == 0 {
+            return 0
         }
 
-        let balance = Self::env().balance();
-        let current_balance = balance.checked_sub(Self::env().minimum_balance()).unwrap_or_default();
         let total_released = self.data().total_released.get_or_default();
-        let total_received = current_balance + total_released;
-        let shares = self.data().shares.get(&account).unwrap();
+        let total_owed = self.data().total_owed.get_or_default();
+        let total_received = current_balance.saturating_sub(total_owed) + total_released;
         let total_shares = self.data().total_shares.get_or_default();
         let released = self.data().released.get(&account).unwrap_or_default();
-        let payment = total_received * shares / total_shares - released;
+        let payment = (total_received * shares / total_shares).saturating_sub(released);
+
+        if payment > 0 {
+            let owed = self.data().owed.get(&account).unwrap_or_default();
+            self.data().owed.insert(&account, &(owed + payment));
+            self.data().total_owed.set(&(total_owed + payment));
+            self.data().released.insert(&account, &(released + payment));
+            self.data().total_released.set(&(total_released + payment));
+        }
+        payment
+    }
+
+    fn _settle_token(&mut self, token: AccountId, account: AccountId, current_balance: Balance) -> Balance {
+        let shares = self.data().shares.get(&account).unwrap_or_default();
+        if shares == 0 {
+            return 0
+        }
+
+        let total_released = self.data().total_released_tokens.get(&token).unwrap_or_default();
+        let total_owed = self.data().total_owed_tokens.get(&token).unwrap_or_default();
+        let total_received = current_balance.saturating_sub(total_owed) + total_released;
+        let total_shares = self.data().total_shares.get_or_default();
+        let released = self.data().released_tokens.get(&(&token, &account)).unwrap_or_default();
+        let payment = (total_received * shares / total_shares).saturating_sub(released);
+
+        if payment > 0 {
+            let owed = self.data().owed_tokens.get(&(&token, &account)).unwrap_or_default();
+            self.data().owed_tokens.insert(&(&token, &account), &(owed + payment));
+            self.data().total_owed_tokens.insert(&token, &(total_owed + payment));
+            self.data()
+                .released_tokens
+                .insert(&(&token, &account), &(released + payment));
+            self.data()
+                .total_released_tokens
+                .insert(&token, &(total_released + payment));
+        }
+        payment
+    }
+
+    fn _settle_all(&mut self) -> Result<(), PaymentSplitterError> {
+        let payees_len = self.data().payees.len();
+        let payees = self.data().payees.iter_range(0, payees_len);
+        let tokens_len = self.data().tokens.len();
+        let tokens = self.data().tokens.iter_range(0, tokens_len);
+
+        let current_balance = Self::env().balance().saturating_sub(Self::env().minimum_balance());
+        for account in payees.iter() {
+            Internal::_settle(self, *account, current_balance);
+        }
+
+        for token in tokens {
+            let current_balance = Internal::_token_balance(self, token)?;
+            for account in payees.iter() {
+                Internal::_settle_token(self, token, *account, current_balance);
+            }
+        }
+        Ok(())
+    }
+
+    fn _rebase_released(&mut self) -> Result<(), PaymentSplitterError> {
+        let total_shares = self.data().total_shares.get_or_default();
+        let total_released = self.data().total_released.get_or_default();
+        let payees_len = self.data().payees.len();
+        let payees = self.data().payees.iter_range(0, payees_len);
+        let tokens_len = self.data().tokens.len();
+        let tokens = self.data().tokens.iter_range(0, tokens_len);
+
+        // Rounding up, so the sum of payments never exceeds the balance of the contract.
+        for account in payees.iter() {
+            let shares = self.data().shares.get(account).unwrap_or_default();
+            let released = mul_div_ceil(total_released, shares, total_shares)
+                .ok_or_else(|| PaymentSplitterError::Custom(String::from("Released amount overflow")))?;
+            self.data().released.insert(account, &released);
+        }
 
+        for token in tokens.iter() {
+            rebase_released_token(self, token, &payees)?;
+        }
+        Ok(())
+    }
+
+    fn _release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError> {
+        // The removed payee can still release the payments credited before the removal
+        if self.data().shares.get(&account).is_none() && self.data().owed.get(&account).is_none() {
+            return Err(PaymentSplitterError::AccountHasNoShares)
+        }
+
+        let current_balance = Self::env().balance().saturating_sub(Self::env().minimum_balance());
+        Internal::_settle(self, account, current_balance);
+
+        let payment = self.data().owed.get(&account).unwrap_or_default();
         if payment == 0 {
             return Err(PaymentSplitterError::AccountIsNotDuePayment)
         }
 
-        self.data().released.insert(&account, &(released + payment));
-        self.data().total_released.set(&(total_released + payment));
+        self.data().owed.remove(&account);
+        let total_owed = self.data().total_owed.get_or_default();
+        self.data().total_owed.set(&(total_owed - payment));
 
         let transfer_result = Self::env().transfer(account, payment);
         if transfer_result.is_err() {
@@ -180,4 +501,53 @@ pub trait InternalImpl: Storage<Data> + Internal {
         Internal::_emit_payment_released_event(self, account, payment);
         Ok(())
     }
+
+    fn _release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError> {
+        if self.data().shares.get(&account).is_none() && self.data().owed_tokens.get(&(&token, &account)).is_none() {
+            return Err(PaymentSplitterError::AccountHasNoShares)
+        }
+
+        let tokens_len = self.data().tokens.len();
+        let is_tracked = self.data().tokens.iter_range(0, tokens_len).contains(&token);
+        if is_tracked {
+            let current_balance = Internal::_token_balance(self, token)?;
+            Internal::_settle_token(self, token, account, current_balance);
+        }
+
+        let payment = self.data().owed_tokens.get(&(&token, &account)).unwrap_or_default();
+        if payment == 0 && !is_tracked {
+            return Err(PaymentSplitterError::TokenIsNotTracked)
+        }
+        if payment == 0 {
+            return Err(PaymentSplitterError::AccountIsNotDuePayment)
+        }
+
+        self.data().owed_tokens.remove(&(&token, &account));
+        let total_owed = self.data().total_owed_tokens.get(&token).unwrap_or_default();
+        self.data().total_owed_tokens.insert(&token, &(total_owed - payment));
+
+        Internal::_transfer_token(self, token, account, payment)?;
+        Internal::_emit_psp22_payment_released_event(self, token, account, payment);
+        Ok(())
+    }
+
+    fn _token_balance(&self, token: AccountId) -> Result<Balance, PaymentSplitterError> {
+        match PSP22Ref::balance_of_builder(&token, Self::env().account_id()).try_invoke() {
+            Ok(Ok(balance)) => Ok(balance),
+            _ => Err(PaymentSplitterError::TokenBalanceFailed(token)),
+        }
+    }
+
+    fn _transfer_token(
+        &mut self,
+        token: AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<(), PaymentSplitterError> {
+        let transfer_result = PSP22Ref::transfer_builder(&token, to, amount, Vec::<u8>::new()).try_invoke();
+        if !matches!(transfer_result, Ok(Ok(Ok(())))) {
+            return Err(PaymentSplitterError::TransferFailed)
+        }
+        Ok(())
+    }
 }
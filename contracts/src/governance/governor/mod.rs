@@ -0,0 +1,645 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use crate::traits::{
+    psp22::extensions::votes::PSP22VotesRef,
+    timelock_controller::TimelockControllerRef,
+};
+pub use crate::{
+    governor,
+    traits::governor::*,
+};
+use core::convert::TryFrom;
+pub use governor::Internal as _;
+use ink::{
+    env::{
+        hash::Blake2x256,
+        CallFlags,
+    },
+    prelude::{
+        vec,
+        vec::Vec,
+    },
+};
+pub use openbrush::traits::{
+    BlockNumber,
+    String,
+};
+use openbrush::{
+    modifier_definition,
+    modifiers,
+    storage::{
+        Mapping,
+        TypeGuard,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Hash,
+        Storage,
+    },
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub token: Option<AccountId>,
+    #[lazy]
+    pub timelock: Option<AccountId>,
+    #[lazy]
+    pub voting_delay: BlockNumber,
+    #[lazy]
+    pub voting_period: BlockNumber,
+    #[lazy]
+    pub proposal_threshold: Balance,
+    #[lazy]
+    pub quorum_numerator: u128,
+    pub proposals: Mapping<ProposalId, ProposalCore>,
+    pub proposal_votes: Mapping<ProposalId, ProposalVote>,
+    pub has_voted: Mapping<(ProposalId, AccountId), (), HasVotedKey>,
+}
+
+pub struct HasVotedKey;
+
+impl<'a> TypeGuard<'a> for HasVotedKey {
+    type Type = &'a (&'a ProposalId, &'a AccountId);
+}
+
+/// The information about the proposal that is kept in the storage.
+#[derive(Debug, Clone, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct ProposalCore {
+    pub proposer: AccountId,
+    /// The block number used to retrieve the voting power. The voting starts after it.
+    pub vote_start: BlockNumber,
+    /// The last block of the voting.
+    pub vote_end: BlockNumber,
+    pub queued: bool,
+    pub executed: bool,
+    pub canceled: bool,
+}
+
+pub const QUORUM_DENOMINATOR: u128 = 100;
+
+/// Modifier to make a function callable only by the timelock, so only
+/// through the proposal that was accepted by the governance.
+#[modifier_definition]
+pub fn only_governance<T, F, R, E>(instance: &mut T, body: F) -> Result<R, E>
+where
+    T: Storage<Data>,
+    F: FnOnce(&mut T) -> Result<R, E>,
+    E: From<GovernorError>,
+{
+    if instance.data().timelock.get_or_default() != Some(T::env().caller()) {
+        return Err(From::from(GovernorError::OnlyGovernance))
+    }
+    body(instance)
+}
+
+pub trait GovernorImpl: Storage<Data> + Internal {
+    fn hash_proposal(&self, transactions: Vec<Transaction>, description_hash: [u8; 32]) -> ProposalId {
+        self._hash_proposal(&transactions, &description_hash)
+    }
+
+    fn state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError> {
+        self._state(proposal_id)
+    }
+
+    fn proposal_snapshot(&self, proposal_id: ProposalId) -> Result<BlockNumber, GovernorError> {
+        Ok(self._proposal(proposal_id)?.vote_start)
+    }
+
+    fn proposal_deadline(&self, proposal_id: ProposalId) -> Result<BlockNumber, GovernorError> {
+        Ok(self._proposal(proposal_id)?.vote_end)
+    }
+
+    fn proposal_proposer(&self, proposal_id: ProposalId) -> Result<AccountId, GovernorError> {
+        Ok(self._proposal(proposal_id)?.proposer)
+    }
+
+    fn proposal_votes(&self, proposal_id: ProposalId) -> ProposalVote {
+        self.data().proposal_votes.get(&proposal_id).unwrap_or_default()
+    }
+
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+        self.data().has_voted.contains(&(&proposal_id, &account))
+    }
+
+    fn token(&self) -> Option<AccountId> {
+        self.data().token.get_or_default()
+    }
+
+    fn timelock(&self) -> Option<AccountId> {
+        self.data().timelock.get_or_default()
+    }
+
+    fn voting_delay(&self) -> BlockNumber {
+        self.data().voting_delay.get_or_default()
+    }
+
+    fn voting_period(&self) -> BlockNumber {
+        self.data().voting_period.get_or_default()
+    }
+
+    fn proposal_threshold(&self) -> Balance {
+        self.data().proposal_threshold.get_or_default()
+    }
+
+    fn quorum(&self, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+        self._quorum(block_number)
+    }
+
+    fn quorum_numerator(&self) -> u128 {
+        self.data().quorum_numerator.get_or_default()
+    }
+
+    fn quorum_denominator(&self) -> u128 {
+        Self::_quorum_denominator()
+    }
+
+    fn get_votes(&self, account: AccountId, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+        self._get_votes(&account, block_number)
+    }
+
+    fn propose(&mut self, transactions: Vec<Transaction>, description: String) -> Result<ProposalId, GovernorError> {
+        let proposer = Self::env().caller();
+        let current_block = Self::env().block_number();
+
+        let threshold = self.data().proposal_threshold.get_or_default();
+        if threshold > 0 {
+            // The votes are taken at the previous block, so nobody has votes at the first block
+            let votes = match current_block.checked_sub(1) {
+                Some(block_number) => self._get_votes(&proposer, block_number)?,
+                None => 0,
+            };
+            if votes < threshold {
+                return Err(GovernorError::InsufficientProposerVotes)
+            }
+        }
+        if transactions.is_empty() {
+            return Err(GovernorError::EmptyProposal)
+        }
+
+        let description_hash = self._hash_description(&description);
+        let proposal_id = self._hash_proposal(&transactions, &description_hash);
+        if self.data().proposals.contains(&proposal_id) {
+            return Err(GovernorError::ProposalAlreadyExists)
+        }
+
+        let vote_start = current_block + self.data().voting_delay.get_or_default();
+        let vote_end = vote_start + self.data().voting_period.get_or_default();
+        self.data().proposals.insert(
+            &proposal_id,
+            &ProposalCore {
+                proposer,
+                vote_start,
+                vote_end,
+                queued: false,
+                executed: false,
+                canceled: false,
+            },
+        );
+
+        self._emit_proposal_created_event(proposal_id, proposer, transactions, vote_start, vote_end, description);
+        Ok(proposal_id)
+    }
+
+    fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType) -> Result<Balance, GovernorError> {
+        let voter = Self::env().caller();
+        if self._state(proposal_id)? != ProposalState::Active {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+
+        let weight = self._get_votes(&voter, self._proposal(proposal_id)?.vote_start)?;
+        self._count_vote(proposal_id, &voter, support, weight)?;
+
+        self._emit_vote_cast_event(proposal_id, voter, support, weight);
+        Ok(weight)
+    }
+
+    fn queue(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError> {
+        let proposal_id = self._hash_proposal(&transactions, &description_hash);
+        if self._state(proposal_id)? != ProposalState::Succeeded {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+
+        let mut proposal = self._proposal(proposal_id)?;
+        proposal.queued = true;
+        self.data().proposals.insert(&proposal_id, &proposal);
+
+        self._schedule_operation(transactions, description_hash)?;
+
+        self._emit_proposal_queued_event(proposal_id);
+        Ok(proposal_id)
+    }
+
+    fn execute(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError> {
+        let proposal_id = self._hash_proposal(&transactions, &description_hash);
+        if self._state(proposal_id)? != ProposalState::Queued {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+
+        let mut proposal = self._proposal(proposal_id)?;
+        proposal.executed = true;
+        self.data().proposals.insert(&proposal_id, &proposal);
+
+        self._execute_operation(transactions, description_hash)?;
+
+        self._emit_proposal_executed_event(proposal_id);
+        Ok(proposal_id)
+    }
+
+    fn cancel(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError> {
+        let proposal_id = self._hash_proposal(&transactions, &description_hash);
+        if self._proposal(proposal_id)?.proposer != Self::env().caller() {
+            return Err(GovernorError::OnlyProposer)
+        }
+        if self._state(proposal_id)? != ProposalState::Pending {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+
+        self._cancel(transactions, description_hash)
+    }
+
+    #[modifiers(only_governance)]
+    fn set_voting_delay(&mut self, new_voting_delay: BlockNumber) -> Result<(), GovernorError> {
+        self._set_voting_delay(new_voting_delay)
+    }
+
+    #[modifiers(only_governance)]
+    fn set_voting_period(&mut self, new_voting_period: BlockNumber) -> Result<(), GovernorError> {
+        self._set_voting_period(new_voting_period)
+    }
+
+    #[modifiers(only_governance)]
+    fn set_proposal_threshold(&mut self, new_proposal_threshold: Balance) -> Result<(), GovernorError> {
+        self._set_proposal_threshold(new_proposal_threshold)
+    }
+
+    #[modifiers(only_governance)]
+    fn update_quorum_numerator(&mut self, new_quorum_numerator: u128) -> Result<(), GovernorError> {
+        self._update_quorum_numerator(new_quorum_numerator)
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_proposal_created_event(
+        &self,
+        proposal_id: ProposalId,
+        proposer: AccountId,
+        transactions: Vec<Transaction>,
+        vote_start: BlockNumber,
+        vote_end: BlockNumber,
+        description: String,
+    );
+
+    fn _emit_vote_cast_event(&self, proposal_id: ProposalId, voter: AccountId, support: VoteType, weight: Balance);
+
+    fn _emit_proposal_queued_event(&self, proposal_id: ProposalId);
+
+    fn _emit_proposal_executed_event(&self, proposal_id: ProposalId);
+
+    fn _emit_proposal_canceled_event(&self, proposal_id: ProposalId);
+
+    fn _init(
+        &mut self,
+        token: AccountId,
+        timelock: AccountId,
+        voting_delay: BlockNumber,
+        voting_period: BlockNumber,
+        proposal_threshold: Balance,
+        quorum_numerator: u128,
+    ) -> Result<(), GovernorError>;
+
+    fn _hash_proposal(&self, transactions: &[Transaction], description_hash: &[u8; 32]) -> ProposalId;
+
+    fn _hash_description(&self, description: &str) -> [u8; 32];
+
+    fn _proposal(&self, proposal_id: ProposalId) -> Result<ProposalCore, GovernorError>;
+
+    fn _state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError>;
+
+    fn _quorum(&self, block_number: BlockNumber) -> Result<Balance, GovernorError>;
+
+    fn _quorum_denominator() -> u128;
+
+    /// Returns whether the amount of `For` and `Abstain` votes reached the quorum.
+    fn _quorum_reached(&self, proposal_id: ProposalId) -> Result<bool, GovernorError>;
+
+    /// Returns whether the amount of `For` votes is bigger than the amount of `Against` votes.
+    fn _vote_succeeded(&self, proposal_id: ProposalId) -> bool;
+
+    /// Registers the vote of the `account` with the given `weight`.
+    fn _count_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        account: &AccountId,
+        support: VoteType,
+        weight: Balance,
+    ) -> Result<(), GovernorError>;
+
+    /// Cancels the proposal that is not executed yet. The operation is also cancelled in the timelock if it was queued.
+    ///
+    /// Emits a `ProposalCanceled` event.
+    fn _cancel(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError>;
+
+    fn _set_voting_delay(&mut self, new_voting_delay: BlockNumber) -> Result<(), GovernorError>;
+
+    fn _set_voting_period(&mut self, new_voting_period: BlockNumber) -> Result<(), GovernorError>;
+
+    fn _set_proposal_threshold(&mut self, new_proposal_threshold: Balance) -> Result<(), GovernorError>;
+
+    fn _update_quorum_numerator(&mut self, new_quorum_numerator: u128) -> Result<(), GovernorError>;
+
+    /// Returns the voting power of the `account` at `block_number` from the token.
+    fn _get_votes(&self, account: &AccountId, block_number: BlockNumber) -> Result<Balance, GovernorError>;
+
+    /// Returns the total supply of the token at `block_number`.
+    fn _get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, GovernorError>;
+
+    /// Schedules the transactions in the timelock with the minimal delay.
+    fn _schedule_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError>;
+
+    /// Executes the scheduled transactions through the timelock.
+    ///
+    /// The transactions are paid from the balance of the timelock. No value is forwarded to the timelock,
+    /// otherwise the unspent value refunded by the timelock would be stuck in the governor.
+    fn _execute_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError>;
+
+    /// Cancels the scheduled transactions in the timelock.
+    fn _cancel_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal {
+    fn _emit_proposal_created_event(
+        &self,
+        _proposal_id: ProposalId,
+        _proposer: AccountId,
+        _transactions: Vec<Transaction>,
+        _vote_start: BlockNumber,
+        _vote_end: BlockNumber,
+        _description: String,
+    ) {
+    }
+
+    fn _emit_vote_cast_event(&self, _proposal_id: ProposalId, _voter: AccountId, _support: VoteType, _weight: Balance) {
+    }
+
+    fn _emit_proposal_queued_event(&self, _proposal_id: ProposalId) {}
+
+    fn _emit_proposal_executed_event(&self, _proposal_id: ProposalId) {}
+
+    fn _emit_proposal_canceled_event(&self, _proposal_id: ProposalId) {}
+
+    fn _init(
+        &mut self,
+        token: AccountId,
+        timelock: AccountId,
+        voting_delay: BlockNumber,
+        voting_period: BlockNumber,
+        proposal_threshold: Balance,
+        quorum_numerator: u128,
+    ) -> Result<(), GovernorError> {
+        self.data().token.set(&Some(token));
+        self.data().timelock.set(&Some(timelock));
+        Internal::_set_voting_delay(self, voting_delay)?;
+        Internal::_set_voting_period(self, voting_period)?;
+        Internal::_set_proposal_threshold(self, proposal_threshold)?;
+        Internal::_update_quorum_numerator(self, quorum_numerator)
+    }
+
+    fn _hash_proposal(&self, transactions: &[Transaction], description_hash: &[u8; 32]) -> ProposalId {
+        let mut hash_data: Vec<u8> = vec![];
+
+        hash_data.append(&mut scale::Encode::encode(&transactions));
+        hash_data.append(&mut scale::Encode::encode(&description_hash));
+
+        Hash::try_from(Self::env().hash_bytes::<Blake2x256>(&hash_data).as_ref()).unwrap()
+    }
+
+    fn _hash_description(&self, description: &str) -> [u8; 32] {
+        Self::env().hash_bytes::<Blake2x256>(description.as_bytes())
+    }
+
+    fn _proposal(&self, proposal_id: ProposalId) -> Result<ProposalCore, GovernorError> {
+        self.data()
+            .proposals
+            .get(&proposal_id)
+            .ok_or(GovernorError::ProposalNotFound)
+    }
+
+    fn _state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError> {
+        let proposal = Internal::_proposal(self, proposal_id)?;
+
+        if proposal.executed {
+            return Ok(ProposalState::Executed)
+        }
+        if proposal.canceled {
+            return Ok(ProposalState::Canceled)
+        }
+
+        let current_block = Self::env().block_number();
+        if proposal.vote_start >= current_block {
+            return Ok(ProposalState::Pending)
+        }
+        if proposal.vote_end >= current_block {
+            return Ok(ProposalState::Active)
+        }
+        if proposal.queued {
+            return Ok(ProposalState::Queued)
+        }
+
+        if Internal::_quorum_reached(self, proposal_id)? && Internal::_vote_succeeded(self, proposal_id) {
+            Ok(ProposalState::Succeeded)
+        } else {
+            Ok(ProposalState::Defeated)
+        }
+    }
+
+    fn _quorum(&self, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+        let total_supply = Internal::_get_past_total_supply(self, block_number)?;
+        let numerator = self.data().quorum_numerator.get_or_default();
+
+        total_supply
+            .checked_mul(numerator)
+            .map(|supply| supply / <Self as Internal>::_quorum_denominator())
+            .ok_or(GovernorError::Custom(String::from("Quorum overflow")))
+    }
+
+    fn _quorum_denominator() -> u128 {
+        QUORUM_DENOMINATOR
+    }
+
+    fn _quorum_reached(&self, proposal_id: ProposalId) -> Result<bool, GovernorError> {
+        let proposal = Internal::_proposal(self, proposal_id)?;
+        let votes = self.data().proposal_votes.get(&proposal_id).unwrap_or_default();
+
+        Ok(Internal::_quorum(self, proposal.vote_start)? <= votes.for_votes + votes.abstain_votes)
+    }
+
+    fn _vote_succeeded(&self, proposal_id: ProposalId) -> bool {
+        let votes = self.data().proposal_votes.get(&proposal_id).unwrap_or_default();
+
+        votes.for_votes > votes.against_votes
+    }
+
+    fn _count_vote(
+        &mut self,
+        proposal_id: ProposalId,
+        account: &AccountId,
+        support: VoteType,
+        weight: Balance,
+    ) -> Result<(), GovernorError> {
+        if self.data().has_voted.contains(&(&proposal_id, account)) {
+            return Err(GovernorError::AlreadyCastVote)
+        }
+        self.data().has_voted.insert(&(&proposal_id, account), &());
+
+        let mut votes = self.data().proposal_votes.get(&proposal_id).unwrap_or_default();
+        match support {
+            VoteType::Against => votes.against_votes += weight,
+            VoteType::For => votes.for_votes += weight,
+            VoteType::Abstain => votes.abstain_votes += weight,
+        }
+        self.data().proposal_votes.insert(&proposal_id, &votes);
+        Ok(())
+    }
+
+    fn _cancel(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError> {
+        let proposal_id = Internal::_hash_proposal(self, &transactions, &description_hash);
+        let state = Internal::_state(self, proposal_id)?;
+        if state == ProposalState::Canceled || state == ProposalState::Executed {
+            return Err(GovernorError::UnexpectedProposalState)
+        }
+
+        let mut proposal = Internal::_proposal(self, proposal_id)?;
+        proposal.canceled = true;
+        self.data().proposals.insert(&proposal_id, &proposal);
+
+        if state == ProposalState::Queued {
+            Internal::_cancel_operation(self, transactions, description_hash)?;
+        }
+
+        Internal::_emit_proposal_canceled_event(self, proposal_id);
+        Ok(proposal_id)
+    }
+
+    fn _set_voting_delay(&mut self, new_voting_delay: BlockNumber) -> Result<(), GovernorError> {
+        self.data().voting_delay.set(&new_voting_delay);
+        Ok(())
+    }
+
+    fn _set_voting_period(&mut self, new_voting_period: BlockNumber) -> Result<(), GovernorError> {
+        if new_voting_period == 0 {
+            return Err(GovernorError::InvalidVotingPeriod)
+        }
+        self.data().voting_period.set(&new_voting_period);
+        Ok(())
+    }
+
+    fn _set_proposal_threshold(&mut self, new_proposal_threshold: Balance) -> Result<(), GovernorError> {
+        self.data().proposal_threshold.set(&new_proposal_threshold);
+        Ok(())
+    }
+
+    fn _update_quorum_numerator(&mut self, new_quorum_numerator: u128) -> Result<(), GovernorError> {
+        if new_quorum_numerator > <Self as Internal>::_quorum_denominator() {
+            return Err(GovernorError::InvalidQuorumFraction)
+        }
+        self.data().quorum_numerator.set(&new_quorum_numerator);
+        Ok(())
+    }
+
+    fn _get_votes(&self, account: &AccountId, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+        if let Some(token) = self.data().token.get_or_default() {
+            Ok(PSP22VotesRef::get_past_votes(&token, *account, block_number)?)
+        } else {
+            Err(GovernorError::NotInitialized)
+        }
+    }
+
+    fn _get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+        if let Some(token) = self.data().token.get_or_default() {
+            Ok(PSP22VotesRef::get_past_total_supply(&token, block_number)?)
+        } else {
+            Err(GovernorError::NotInitialized)
+        }
+    }
+
+    fn _schedule_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError> {
+        if let Some(timelock) = self.data().timelock.get_or_default() {
+            let delay = TimelockControllerRef::get_min_delay(&timelock);
+            TimelockControllerRef::schedule_batch_builder(&timelock, transactions, None, salt, delay)
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()
+                .unwrap()
+                .unwrap()?;
+            Ok(())
+        } else {
+            Err(GovernorError::NotInitialized)
+        }
+    }
+
+    fn _execute_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError> {
+        if let Some(timelock) = self.data().timelock.get_or_default() {
+            TimelockControllerRef::execute_batch_builder(&timelock, transactions, None, salt)
+                .call_flags(CallFlags::default().set_allow_reentry(true))
+                .try_invoke()
+                .unwrap()
+                .unwrap()?;
+            Ok(())
+        } else {
+            Err(GovernorError::NotInitialized)
+        }
+    }
+
+    fn _cancel_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError> {
+        if let Some(timelock) = self.data().timelock.get_or_default() {
+            let id = TimelockControllerRef::hash_operation_batch(&timelock, transactions, None, salt);
+            TimelockControllerRef::cancel(&timelock, id)?;
+            Ok(())
+        } else {
+            Err(GovernorError::NotInitialized)
+        }
+    }
+}
@@ -32,7 +32,10 @@ pub use access_control::{
     Internal as _,
     InternalImpl as _,
 };
-use core::convert::TryFrom;
+use core::{
+    cmp::min,
+    convert::TryFrom,
+};
 use ink::{
     env::{
         call::{
@@ -52,9 +55,13 @@ use ink::{
 use openbrush::{
     modifier_definition,
     modifiers,
-    storage::Mapping,
+    storage::{
+        Mapping,
+        MultiMapping,
+    },
     traits::{
         AccountId,
+        Balance,
         Hash,
         Storage,
         Timestamp,
@@ -67,9 +74,16 @@ pub use timelock_controller::Internal as _;
 pub struct Data {
     #[lazy]
     pub min_delay: Timestamp,
+    #[lazy]
+    pub grace_period: Timestamp,
     pub timestamps: Mapping<OperationId, Timestamp>,
+    pub operations: Mapping<OperationId, Operation>,
+    pub pending_operations: MultiMapping<(), OperationId>,
 }
 
+/// Maximum number of operation ids returned by one page of `pending_operations`.
+pub const MAX_PAGE_SIZE: u128 = 100;
+
 /// Modifier to make a function callable only by a certain role. In
 /// addition to checking the sender's role, zero account's role is also
 /// considered. Granting a role to zero account is equivalent to enabling
@@ -112,10 +126,42 @@ pub trait TimelockControllerImpl:
         self._is_operation_done(id)
     }
 
+    fn is_operation_expired(&self, id: OperationId) -> bool {
+        self._is_operation_expired(id)
+    }
+
     fn get_timestamp(&self, id: OperationId) -> Timestamp {
         self._get_timestamp(id)
     }
 
+    fn get_grace_period(&self) -> Timestamp {
+        self.data::<Data>().grace_period.get_or_default()
+    }
+
+    fn get_operation_state(&self, id: OperationId) -> OperationState {
+        self._get_operation_state(id)
+    }
+
+    fn get_operation(&self, id: OperationId) -> Option<Operation> {
+        self.data::<Data>().operations.get(&id)
+    }
+
+    fn pending_operations(&self, start: u128, limit: u128) -> Vec<OperationId> {
+        let pending_operations = &self.data::<Data>().pending_operations;
+        let end = min(
+            start.saturating_add(min(limit, MAX_PAGE_SIZE)),
+            pending_operations.count(&()),
+        );
+
+        (start..end)
+            .filter_map(|index| pending_operations.get_value(&(), &index))
+            .collect()
+    }
+
+    fn pending_operations_count(&self) -> u128 {
+        self.data::<Data>().pending_operations.count(&())
+    }
+
     fn get_min_delay(&self) -> Timestamp {
         self.data::<Data>().min_delay.get_or_default()
     }
@@ -144,6 +190,14 @@ pub trait TimelockControllerImpl:
         let id = self._hash_operation(&transaction, &predecessor, &salt);
 
         self._schedule(id, &delay)?;
+        self._store_operation(
+            id,
+            &Operation {
+                transactions: vec![transaction.clone()],
+                predecessor,
+                salt,
+            },
+        );
 
         self._emit_call_scheduled_event(id, 0, transaction, predecessor, delay);
         Ok(())
@@ -160,6 +214,14 @@ pub trait TimelockControllerImpl:
         let id = self._hash_operation_batch(&transactions, &predecessor, &salt);
 
         self._schedule(id, &delay)?;
+        self._store_operation(
+            id,
+            &Operation {
+                transactions: transactions.clone(),
+                predecessor,
+                salt,
+            },
+        );
 
         for (i, transaction) in transactions.into_iter().enumerate() {
             self._emit_call_scheduled_event(id, i as u8, transaction, predecessor, delay);
@@ -173,6 +235,7 @@ pub trait TimelockControllerImpl:
             return Err(TimelockControllerError::OperationCannonBeCanceled)
         }
         self.data::<Data>().timestamps.remove(&id);
+        self.data::<Data>().pending_operations.remove_value(&(), &id);
 
         self._emit_cancelled_event(id);
         Ok(())
@@ -188,8 +251,10 @@ pub trait TimelockControllerImpl:
         let id = self._hash_operation(&transaction, &predecessor, &salt);
 
         self._before_call(predecessor)?;
+        let spent = self._check_transferred_value(core::slice::from_ref(&transaction))?;
         self._call(id, 0, transaction)?;
-        self._after_call(id)
+        self._after_call(id)?;
+        self._refund_unspent_value(spent)
     }
 
     #[modifiers(only_role_or_open_role(<Self as Internal>::_executor_role()))]
@@ -202,11 +267,13 @@ pub trait TimelockControllerImpl:
         let id = self._hash_operation_batch(&transactions, &predecessor, &salt);
 
         self._before_call(predecessor)?;
+        let spent = self._check_transferred_value(&transactions)?;
 
         for (i, transaction) in transactions.into_iter().enumerate() {
             self._call(id, i as u8, transaction)?;
         }
-        self._after_call(id)
+        self._after_call(id)?;
+        self._refund_unspent_value(spent)
     }
 
     fn update_delay(&mut self, new_delay: Timestamp) -> Result<(), TimelockControllerError> {
@@ -220,12 +287,26 @@ pub trait TimelockControllerImpl:
         self.data::<Data>().min_delay.set(&new_delay);
         Ok(())
     }
+
+    fn update_grace_period(&mut self, new_grace_period: Timestamp) -> Result<(), TimelockControllerError> {
+        if Self::env().account_id() != Self::env().caller() {
+            return Err(TimelockControllerError::CallerMustBeTimeLock)
+        }
+
+        let old_grace_period = self.data::<Data>().grace_period.get_or_default();
+        self._emit_grace_period_change_event(old_grace_period, new_grace_period);
+
+        self.data::<Data>().grace_period.set(&new_grace_period);
+        Ok(())
+    }
 }
 
 pub trait Internal {
     /// User must override those methods in their contract.
     fn _emit_min_delay_change_event(&self, old_delay: Timestamp, new_delay: Timestamp);
 
+    fn _emit_grace_period_change_event(&self, old_grace_period: Timestamp, new_grace_period: Timestamp);
+
     fn _emit_call_scheduled_event(
         &self,
         id: OperationId,
@@ -237,7 +318,7 @@ pub trait Internal {
 
     fn _emit_cancelled_event(&self, id: OperationId);
 
-    fn _emit_call_executed_event(&self, id: OperationId, index: u8, transaction: Transaction);
+    fn _emit_call_executed_event(&self, id: OperationId, index: u8, transaction: Transaction, output: Vec<u8>);
 
     fn _init_with_caller(&mut self, min_delay: Timestamp, proposers: Vec<AccountId>, executors: Vec<AccountId>);
 
@@ -266,6 +347,9 @@ pub trait Internal {
     /// Schedule an operation that is to become valid after a given delay.
     fn _schedule(&mut self, id: OperationId, delay: &Timestamp) -> Result<(), TimelockControllerError>;
 
+    /// Stores the payload of the scheduled operation, so it can be queried by `get_operation`.
+    fn _store_operation(&mut self, id: OperationId, operation: &Operation);
+
     /// Checks before execution of an operation's calls.
     fn _before_call(&self, predecessor: Option<OperationId>) -> Result<(), TimelockControllerError>;
 
@@ -274,9 +358,17 @@ pub trait Internal {
 
     /// Execute an operation's call.
     ///
-    /// Emits a `CallExecuted` event.
+    /// Emits a `CallExecuted` event with the raw output of the callee.
     fn _call(&mut self, id: OperationId, index: u8, transaction: Transaction) -> Result<(), TimelockControllerError>;
 
+    /// Checks that the balance of the timelock covers the value transferred by transactions.
+    ///
+    /// Returns the sum of `transferred_value` of transactions.
+    fn _check_transferred_value(&self, transactions: &[Transaction]) -> Result<Balance, TimelockControllerError>;
+
+    /// Refunds the part of the value sent by the caller that was not transferred by transactions.
+    fn _refund_unspent_value(&mut self, spent: Balance) -> Result<(), TimelockControllerError>;
+
     fn _timelock_admin_role() -> RoleType;
 
     fn _proposal_role() -> RoleType;
@@ -291,12 +383,18 @@ pub trait Internal {
 
     fn _is_operation_done(&self, id: OperationId) -> bool;
 
+    fn _is_operation_expired(&self, id: OperationId) -> bool;
+
     fn _get_timestamp(&self, id: OperationId) -> Timestamp;
+
+    fn _get_operation_state(&self, id: OperationId) -> OperationState;
 }
 
 pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
     fn _emit_min_delay_change_event(&self, _old_delay: Timestamp, _new_delay: Timestamp) {}
 
+    fn _emit_grace_period_change_event(&self, _old_grace_period: Timestamp, _new_grace_period: Timestamp) {}
+
     fn _emit_call_scheduled_event(
         &self,
         _id: OperationId,
@@ -309,7 +407,7 @@ pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
 
     fn _emit_cancelled_event(&self, _id: OperationId) {}
 
-    fn _emit_call_executed_event(&self, _id: OperationId, _index: u8, _transaction: Transaction) {}
+    fn _emit_call_executed_event(&self, _id: OperationId, _index: u8, _transaction: Transaction, _output: Vec<u8>) {}
 
     fn _init_with_caller(&mut self, min_delay: Timestamp, proposers: Vec<AccountId>, executors: Vec<AccountId>) {
         Internal::_init_with_admin(self, Some(Self::env().caller()), min_delay, proposers, executors);
@@ -401,9 +499,14 @@ pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
         self.data::<Data>()
             .timestamps
             .insert(&id, &(Self::env().block_timestamp() + delay));
+        self.data::<Data>().pending_operations.insert(&(), &id);
         Ok(())
     }
 
+    fn _store_operation(&mut self, id: OperationId, operation: &Operation) {
+        self.data::<Data>().operations.insert(&id, operation);
+    }
+
     fn _before_call(&self, predecessor: Option<OperationId>) -> Result<(), TimelockControllerError> {
         if predecessor.is_some() && !Internal::_is_operation_done(self, predecessor.unwrap()) {
             return Err(TimelockControllerError::MissingDependency)
@@ -412,6 +515,9 @@ pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
     }
 
     fn _after_call(&mut self, id: OperationId) -> Result<(), TimelockControllerError> {
+        if Internal::_is_operation_expired(self, id) {
+            return Err(TimelockControllerError::OperationExpired)
+        }
         if !Internal::_is_operation_ready(self, id) {
             return Err(TimelockControllerError::OperationIsNotReady)
         }
@@ -419,6 +525,7 @@ pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
         self.data::<Data>()
             .timestamps
             .insert(&id, &<Self as Internal>::_done_timestamp());
+        self.data::<Data>().pending_operations.remove_value(&(), &id);
         Ok(())
     }
 
@@ -431,7 +538,7 @@ pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
                         .transferred_value(transaction.transferred_value),
                 )
                 .exec_input(ExecutionInput::new(transaction.selector.into()).push_arg(CallInput(&transaction.input)))
-                .returns::<()>()
+                .returns::<CallOutput>()
                 .call_flags(CallFlags::default().set_allow_reentry(true))
                 .try_invoke()
                 .map_err(|_| TimelockControllerError::UnderlyingTransactionReverted)
@@ -439,8 +546,33 @@ pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
             Err(TimelockControllerError::CalleeZeroAddress)
         };
 
-        result?.unwrap();
-        Internal::_emit_call_executed_event(self, id, i, transaction);
+        let CallOutput(output) = result?.unwrap();
+        Internal::_emit_call_executed_event(self, id, i, transaction, output);
+        Ok(())
+    }
+
+    fn _check_transferred_value(&self, transactions: &[Transaction]) -> Result<Balance, TimelockControllerError> {
+        let total = transactions
+            .iter()
+            .try_fold(Balance::default(), |total, transaction| {
+                total.checked_add(transaction.transferred_value)
+            })
+            .ok_or(TimelockControllerError::InsufficientBalance)?;
+
+        let available = Self::env().balance().saturating_sub(Self::env().minimum_balance());
+        if total > available {
+            return Err(TimelockControllerError::InsufficientBalance)
+        }
+        Ok(total)
+    }
+
+    fn _refund_unspent_value(&mut self, spent: Balance) -> Result<(), TimelockControllerError> {
+        let unspent = Self::env().transferred_value().saturating_sub(spent);
+        if unspent > 0 {
+            Self::env()
+                .transfer(Self::env().caller(), unspent)
+                .map_err(|_| TimelockControllerError::TransferFailed)?;
+        }
         Ok(())
     }
 
@@ -466,16 +598,50 @@ pub trait InternalImpl: Internal + Storage<Data> + access_control::Internal {
 
     fn _is_operation_ready(&self, id: OperationId) -> bool {
         let timestamp = Internal::_get_timestamp(self, id);
-        timestamp > <Self as Internal>::_done_timestamp() && timestamp <= Self::env().block_timestamp()
+        timestamp > <Self as Internal>::_done_timestamp()
+            && timestamp <= Self::env().block_timestamp()
+            && !Internal::_is_operation_expired(self, id)
     }
 
     fn _is_operation_done(&self, id: OperationId) -> bool {
         Internal::_get_timestamp(self, id) == <Self as Internal>::_done_timestamp()
     }
 
+    fn _is_operation_expired(&self, id: OperationId) -> bool {
+        let grace_period = self.data::<Data>().grace_period.get_or_default();
+        if grace_period == Timestamp::default() {
+            return false
+        }
+
+        let timestamp = Internal::_get_timestamp(self, id);
+        timestamp > <Self as Internal>::_done_timestamp()
+            && timestamp.saturating_add(grace_period) <= Self::env().block_timestamp()
+    }
+
     fn _get_timestamp(&self, id: OperationId) -> Timestamp {
         self.data::<Data>().timestamps.get(&id).unwrap_or(Timestamp::default())
     }
+
+    fn _get_operation_state(&self, id: OperationId) -> OperationState {
+        let timestamp = Internal::_get_timestamp(self, id);
+
+        if timestamp == Timestamp::default() {
+            // The payload is kept after the cancellation
+            if self.data::<Data>().operations.contains(&id) {
+                OperationState::Cancelled
+            } else {
+                OperationState::Unset
+            }
+        } else if timestamp == <Self as Internal>::_done_timestamp() {
+            OperationState::Done
+        } else if timestamp > Self::env().block_timestamp() {
+            OperationState::Waiting
+        } else if Internal::_is_operation_expired(self, id) {
+            OperationState::Expired
+        } else {
+            OperationState::Ready
+        }
+    }
 }
 
 /// A wrapper that allows us to encode a blob of bytes.
@@ -488,3 +654,17 @@ impl<'a> scale::Encode for CallInput<'a> {
         dest.write(self.0);
     }
 }
+
+/// A wrapper that allows us to decode a blob of bytes.
+///
+/// We use this to get the untyped (bytes) output of the callee from the `CallBuilder`.
+pub struct CallOutput(pub Vec<u8>);
+
+impl scale::Decode for CallOutput {
+    fn decode<I: scale::Input>(input: &mut I) -> Result<Self, scale::Error> {
+        let len = input.remaining_len()?.unwrap_or_default();
+        let mut output = vec![0; len];
+        input.read(&mut output)?;
+        Ok(CallOutput(output))
+    }
+}
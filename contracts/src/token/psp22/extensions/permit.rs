@@ -0,0 +1,167 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::permit,
+    traits::psp22::{
+        extensions::permit::*,
+        *,
+    },
+};
+use ink::env::hash::Blake2x256;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+pub use permit::Internal as _;
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub nonces: Mapping<AccountId, u64>,
+}
+
+/// Prefix of the domain separator, that separates permits of this extension from other signed messages
+pub const PERMIT_DOMAIN: &[u8] = b"PSP22Permit";
+
+/// Signing context of sr25519 signatures, the same as the one used by substrate accounts
+pub const SR25519_SIGNING_CONTEXT: &[u8] = b"substrate";
+
+pub trait PSP22PermitImpl: Storage<Data> + Internal + psp22::Internal {
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        if Self::env().block_timestamp() > deadline {
+            return Err(PSP22Error::PermitExpired)
+        }
+
+        let nonce = self.data().nonces.get(&owner).unwrap_or_default();
+        let message_hash = self._permit_message_hash(&owner, &spender, &value, nonce, &deadline);
+        self._verify_signature(&owner, &message_hash, &signature)?;
+        self.data().nonces.insert(&owner, &(nonce + 1));
+
+        psp22::Internal::_approve_from_to(self, owner, spender, value)
+    }
+
+    fn nonces(&self, owner: AccountId) -> u64 {
+        self.data().nonces.get(&owner).unwrap_or_default()
+    }
+
+    fn domain_separator(&self) -> [u8; 32] {
+        self._domain_separator()
+    }
+}
+
+pub trait Internal {
+    fn _domain_separator(&self) -> [u8; 32];
+
+    /// Returns the hash of the message that `owner` signs to permit the allowance.
+    fn _permit_message_hash(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        nonce: u64,
+        deadline: &Timestamp,
+    ) -> [u8; 32];
+
+    /// Checks that `signature` of `message_hash` is made by `owner`.
+    ///
+    /// ink! 4 doesn't provide the `sr25519_verify` host function, so sr25519 signatures are verified by the contract.
+    fn _verify_signature(
+        &self,
+        owner: &AccountId,
+        message_hash: &[u8; 32],
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal {
+    fn _domain_separator(&self) -> [u8; 32] {
+        let encoded = scale::Encode::encode(&(PERMIT_DOMAIN, Self::env().account_id()));
+        Self::env().hash_bytes::<Blake2x256>(&encoded)
+    }
+
+    fn _permit_message_hash(
+        &self,
+        owner: &AccountId,
+        spender: &AccountId,
+        value: &Balance,
+        nonce: u64,
+        deadline: &Timestamp,
+    ) -> [u8; 32] {
+        let encoded = scale::Encode::encode(&(
+            Internal::_domain_separator(self),
+            owner,
+            spender,
+            value,
+            nonce,
+            deadline,
+        ));
+        Self::env().hash_bytes::<Blake2x256>(&encoded)
+    }
+
+    fn _verify_signature(
+        &self,
+        owner: &AccountId,
+        message_hash: &[u8; 32],
+        signature: &PermitSignature,
+    ) -> Result<(), PSP22Error> {
+        match signature {
+            PermitSignature::Ecdsa(signature) => {
+                let mut public_key = [0u8; 33];
+                ink::env::ecdsa_recover(signature, message_hash, &mut public_key).map_err(|_| PSP22Error::PermitInvalidSignature)?;
+
+                // the account of ECDSA key is the hash of the compressed public key
+                let signer = AccountId::from(Self::env().hash_bytes::<Blake2x256>(&public_key));
+                if &signer != owner {
+                    return Err(PSP22Error::PermitInvalidSignature)
+                }
+                Ok(())
+            }
+            PermitSignature::Sr25519(signature) => {
+                // the account of sr25519 key is the public key
+                let public_key =
+                    schnorrkel::PublicKey::from_bytes(AsRef::<[u8]>::as_ref(owner)).map_err(|_| PSP22Error::PermitInvalidSignature)?;
+                let signature = schnorrkel::Signature::from_bytes(signature).map_err(|_| PSP22Error::PermitInvalidSignature)?;
+                public_key
+                    .verify_simple(SR25519_SIGNING_CONTEXT, message_hash, &signature)
+                    .map_err(|_| PSP22Error::PermitInvalidSignature)
+            }
+        }
+    }
+}
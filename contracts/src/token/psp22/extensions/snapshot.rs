@@ -0,0 +1,206 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::snapshot,
+    traits::psp22::{
+        extensions::snapshot::*,
+        *,
+    },
+};
+use openbrush::{
+    storage::{
+        Mapping,
+        TypeGuard,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+pub use snapshot::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub current_snapshot_id: SnapshotId,
+    /// Snapshots of balances. `None` owner holds the snapshots of the total supply.
+    pub snapshots: Mapping<(Option<AccountId>, u32), Snapshot, SnapshotsKey>,
+    pub num_snapshots: Mapping<Option<AccountId>, u32, NumSnapshotsKey>,
+}
+
+pub struct SnapshotsKey;
+
+impl<'a> TypeGuard<'a> for SnapshotsKey {
+    type Type = &'a (Option<&'a AccountId>, u32);
+}
+
+pub struct NumSnapshotsKey;
+
+impl<'a> TypeGuard<'a> for NumSnapshotsKey {
+    type Type = &'a Option<&'a AccountId>;
+}
+
+/// Value that the owner had when the snapshot `id` was taken.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct Snapshot {
+    pub id: SnapshotId,
+    pub value: Balance,
+}
+
+pub trait PSP22SnapshotImpl: Storage<Data> + Internal {
+    fn balance_of_at(&self, account: AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        self._balance_of_at(&account, snapshot_id)
+    }
+
+    fn total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        self._total_supply_at(snapshot_id)
+    }
+}
+
+pub trait Internal {
+    /// User must override this method in their contract.
+    fn _emit_snapshot_event(&self, _id: SnapshotId);
+
+    /// Creates a new snapshot and returns its id.
+    ///
+    /// Snapshots are cheap, because the values are recorded lazily,
+    /// only when the balance of the account changes after the snapshot.
+    fn _snapshot(&mut self) -> SnapshotId;
+
+    fn _current_snapshot_id(&self) -> SnapshotId;
+
+    fn _balance_of_at(&self, account: &AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+
+    fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+
+    /// Records the values of `from`, `to` and the total supply before they are changed.
+    ///
+    /// Is called from `psp22::Internal::_before_token_transfer`.
+    fn _update_snapshots(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal {
+    fn _emit_snapshot_event(&self, _id: SnapshotId) {}
+
+    fn _snapshot(&mut self) -> SnapshotId {
+        let id = self.data().current_snapshot_id.get_or_default() + 1;
+        self.data().current_snapshot_id.set(&id);
+        Internal::_emit_snapshot_event(self, id);
+        id
+    }
+
+    fn _current_snapshot_id(&self) -> SnapshotId {
+        self.data().current_snapshot_id.get_or_default()
+    }
+
+    fn _balance_of_at(&self, account: &AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        check_snapshot_id(snapshot_id, Internal::_current_snapshot_id(self))?;
+
+        Ok(value_at(self.data(), Some(account), snapshot_id)
+            .unwrap_or_else(|| psp22::Internal::_balance_of(self, account)))
+    }
+
+    fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+        check_snapshot_id(snapshot_id, Internal::_current_snapshot_id(self))?;
+
+        Ok(value_at(self.data(), None, snapshot_id).unwrap_or_else(|| psp22::Internal::_total_supply(self)))
+    }
+
+    fn _update_snapshots(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        _amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        let current_id = Internal::_current_snapshot_id(self);
+
+        if from.is_none() || to.is_none() {
+            let total_supply = psp22::Internal::_total_supply(self);
+            update_snapshot(self.data(), None, total_supply, current_id);
+        }
+        for account in [from, to].into_iter().flatten() {
+            let balance = psp22::Internal::_balance_of(self, account);
+            update_snapshot(self.data(), Some(account), balance, current_id);
+        }
+        Ok(())
+    }
+}
+
+fn check_snapshot_id(snapshot_id: SnapshotId, current_id: SnapshotId) -> Result<(), PSP22Error> {
+    if snapshot_id == 0 || snapshot_id > current_id {
+        return Err(PSP22Error::Custom(String::from("Nonexistent snapshot")))
+    }
+    Ok(())
+}
+
+/// Returns the value of the first snapshot of `owner` taken at or after `snapshot_id`.
+/// `None` means that the value was not changed since the snapshot, so the current value must be used.
+fn value_at(data: &Data, owner: Option<&AccountId>, snapshot_id: SnapshotId) -> Option<Balance> {
+    let mut low = 0;
+    let mut high = data.num_snapshots.get(&owner).unwrap_or(0);
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let snapshot = data.snapshots.get(&(owner, mid)).unwrap_or_default();
+        if snapshot.id < snapshot_id {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    data.snapshots.get(&(owner, low)).map(|snapshot| snapshot.value)
+}
+
+/// Records `value` of `owner` for the current snapshot, if it is not recorded yet.
+fn update_snapshot(data: &mut Data, owner: Option<&AccountId>, value: Balance, current_id: SnapshotId) {
+    if current_id == 0 {
+        return
+    }
+
+    let len = data.num_snapshots.get(&owner).unwrap_or(0);
+    let last_id = match len {
+        0 => 0,
+        len => data.snapshots.get(&(owner, len - 1)).unwrap_or_default().id,
+    };
+
+    if last_id < current_id {
+        data.snapshots
+            .insert(&(owner, len), &Snapshot { id: current_id, value });
+        data.num_snapshots.insert(&owner, &(len + 1));
+    }
+}
@@ -0,0 +1,297 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp22,
+    psp22::extensions::votes,
+    traits::psp22::{
+        extensions::votes::*,
+        *,
+    },
+};
+pub use openbrush::traits::BlockNumber;
+use openbrush::{
+    storage::{
+        Mapping,
+        TypeGuard,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        String,
+    },
+};
+pub use psp22::{
+    Internal as _,
+    InternalImpl as _,
+    PSP22Impl,
+};
+pub use votes::Internal as _;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    pub delegates: Mapping<AccountId, AccountId>,
+    /// Checkpoints of the voting power. `None` owner holds the history of the total supply.
+    pub checkpoints: Mapping<(Option<AccountId>, u32), Checkpoint, CheckpointsKey>,
+    pub num_checkpoints: Mapping<Option<AccountId>, u32, NumCheckpointsKey>,
+}
+
+pub struct CheckpointsKey;
+
+impl<'a> TypeGuard<'a> for CheckpointsKey {
+    type Type = &'a (Option<&'a AccountId>, u32);
+}
+
+pub struct NumCheckpointsKey;
+
+impl<'a> TypeGuard<'a> for NumCheckpointsKey {
+    type Type = &'a Option<&'a AccountId>;
+}
+
+/// Amount of votes that the owner had since `block_number`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct Checkpoint {
+    pub block_number: BlockNumber,
+    pub votes: Balance,
+}
+
+pub trait PSP22VotesImpl: Storage<Data> + Internal {
+    fn delegates(&self, account: AccountId) -> Option<AccountId> {
+        self._delegates(&account)
+    }
+
+    fn get_votes(&self, account: AccountId) -> Balance {
+        self._get_votes(&account)
+    }
+
+    fn get_past_votes(&self, account: AccountId, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+        self._get_past_votes(&account, block_number)
+    }
+
+    fn get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+        self._get_past_total_supply(block_number)
+    }
+
+    fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error> {
+        let delegator = Self::env().caller();
+        self._delegate(&delegator, Some(delegatee))
+    }
+}
+
+pub trait Internal {
+    /// User must override those methods in their contract.
+    fn _emit_delegate_changed_event(
+        &self,
+        _delegator: AccountId,
+        _from_delegate: Option<AccountId>,
+        _to_delegate: Option<AccountId>,
+    );
+
+    fn _emit_delegate_votes_changed_event(&self, _delegate: AccountId, _previous_votes: Balance, _new_votes: Balance);
+
+    fn _delegates(&self, account: &AccountId) -> Option<AccountId>;
+
+    fn _get_votes(&self, account: &AccountId) -> Balance;
+
+    fn _get_past_votes(&self, account: &AccountId, block_number: BlockNumber) -> Result<Balance, PSP22Error>;
+
+    fn _get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, PSP22Error>;
+
+    /// Changes the delegate of `delegator` and moves its whole balance of votes to `delegatee`.
+    fn _delegate(&mut self, delegator: &AccountId, delegatee: Option<AccountId>) -> Result<(), PSP22Error>;
+
+    /// Updates the history of the total supply and moves votes between delegates of `from` and `to`.
+    ///
+    /// Is called from `psp22::Internal::_after_token_transfer`.
+    fn _transfer_voting_units(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+
+    fn _move_delegate_votes(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error>;
+}
+
+pub trait InternalImpl: Storage<Data> + Internal + psp22::Internal {
+    fn _emit_delegate_changed_event(
+        &self,
+        _delegator: AccountId,
+        _from_delegate: Option<AccountId>,
+        _to_delegate: Option<AccountId>,
+    ) {
+    }
+
+    fn _emit_delegate_votes_changed_event(&self, _delegate: AccountId, _previous_votes: Balance, _new_votes: Balance) {}
+
+    fn _delegates(&self, account: &AccountId) -> Option<AccountId> {
+        self.data().delegates.get(account)
+    }
+
+    fn _get_votes(&self, account: &AccountId) -> Balance {
+        latest(self.data(), Some(account))
+    }
+
+    fn _get_past_votes(&self, account: &AccountId, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+        if block_number >= Self::env().block_number() {
+            return Err(PSP22Error::Custom(String::from("Block not yet mined")))
+        }
+        Ok(upper_lookup(self.data(), Some(account), block_number))
+    }
+
+    fn _get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+        if block_number >= Self::env().block_number() {
+            return Err(PSP22Error::Custom(String::from("Block not yet mined")))
+        }
+        Ok(upper_lookup(self.data(), None, block_number))
+    }
+
+    fn _delegate(&mut self, delegator: &AccountId, delegatee: Option<AccountId>) -> Result<(), PSP22Error> {
+        let current_delegate = Internal::_delegates(self, delegator);
+
+        match &delegatee {
+            Some(delegatee) => self.data().delegates.insert(delegator, delegatee),
+            None => self.data().delegates.remove(delegator),
+        }
+
+        Internal::_emit_delegate_changed_event(self, *delegator, current_delegate, delegatee);
+
+        let balance = psp22::Internal::_balance_of(self, delegator);
+        Internal::_move_delegate_votes(self, current_delegate.as_ref(), delegatee.as_ref(), &balance)
+    }
+
+    fn _transfer_voting_units(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if from.is_none() {
+            let new_supply = latest(self.data(), None) + amount;
+            push_checkpoint(self.data(), None, new_supply, Self::env().block_number());
+        }
+        if to.is_none() {
+            let new_supply = latest(self.data(), None) - amount;
+            push_checkpoint(self.data(), None, new_supply, Self::env().block_number());
+        }
+
+        let from_delegate = from.and_then(|from| Internal::_delegates(self, from));
+        let to_delegate = to.and_then(|to| Internal::_delegates(self, to));
+        Internal::_move_delegate_votes(self, from_delegate.as_ref(), to_delegate.as_ref(), amount)
+    }
+
+    fn _move_delegate_votes(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        amount: &Balance,
+    ) -> Result<(), PSP22Error> {
+        if from == to || *amount == 0 {
+            return Ok(())
+        }
+
+        if let Some(from) = from {
+            let previous_votes = latest(self.data(), Some(from));
+            if previous_votes < *amount {
+                return Err(PSP22Error::InsufficientBalance)
+            }
+            let new_votes = previous_votes - amount;
+            push_checkpoint(self.data(), Some(from), new_votes, Self::env().block_number());
+            Internal::_emit_delegate_votes_changed_event(self, *from, previous_votes, new_votes);
+        }
+
+        if let Some(to) = to {
+            let previous_votes = latest(self.data(), Some(to));
+            let new_votes = previous_votes + amount;
+            push_checkpoint(self.data(), Some(to), new_votes, Self::env().block_number());
+            Internal::_emit_delegate_votes_changed_event(self, *to, previous_votes, new_votes);
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the votes of the most recent checkpoint of `owner`.
+fn latest(data: &Data, owner: Option<&AccountId>) -> Balance {
+    match data.num_checkpoints.get(&owner).unwrap_or(0) {
+        0 => 0,
+        len => {
+            data.checkpoints
+                .get(&(owner, len - 1))
+                .map(|checkpoint| checkpoint.votes)
+                .unwrap_or(0)
+        }
+    }
+}
+
+/// Returns the votes of the last checkpoint of `owner` created at or before `block_number`.
+fn upper_lookup(data: &Data, owner: Option<&AccountId>, block_number: BlockNumber) -> Balance {
+    let mut low = 0;
+    let mut high = data.num_checkpoints.get(&owner).unwrap_or(0);
+
+    while low < high {
+        let mid = low + (high - low) / 2;
+        let checkpoint = data.checkpoints.get(&(owner, mid)).unwrap_or_default();
+        if checkpoint.block_number > block_number {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    match high {
+        0 => 0,
+        high => {
+            data.checkpoints
+                .get(&(owner, high - 1))
+                .map(|checkpoint| checkpoint.votes)
+                .unwrap_or(0)
+        }
+    }
+}
+
+/// Records `votes` for `owner` at `block_number`. Several updates within
+/// one block overwrite the same checkpoint.
+fn push_checkpoint(data: &mut Data, owner: Option<&AccountId>, votes: Balance, block_number: BlockNumber) {
+    let len = data.num_checkpoints.get(&owner).unwrap_or(0);
+
+    if len > 0 {
+        if let Some(mut last) = data.checkpoints.get(&(owner, len - 1)) {
+            if last.block_number == block_number {
+                last.votes = votes;
+                data.checkpoints.insert(&(owner, len - 1), &last);
+                return
+            }
+        }
+    }
+
+    data.checkpoints
+        .insert(&(owner, len), &Checkpoint { block_number, votes });
+    data.num_checkpoints.insert(&owner, &(len + 1));
+}
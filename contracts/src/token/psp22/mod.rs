@@ -29,6 +29,10 @@ pub mod extensions {
     pub mod flashmint;
     pub mod metadata;
     pub mod mintable;
+    #[cfg(feature = "psp22_permit")]
+    pub mod permit;
+    pub mod snapshot;
+    pub mod votes;
     pub mod wrapper;
 }
 
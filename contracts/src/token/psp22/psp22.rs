@@ -23,7 +23,10 @@ pub use crate::{
     psp22,
     traits::psp22::*,
 };
-use ink::prelude::vec::Vec;
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+};
 use openbrush::{
     storage::{
         Mapping,
@@ -150,6 +153,22 @@ pub trait Internal {
         _amount: &Balance,
     ) -> Result<(), PSP22Error>;
 
+    /// Returns whether `before_received` of the recipient is called on transfers and mints.
+    ///
+    /// The safe transfer check is disabled by default, override this method to enable it.
+    fn _is_safe_transfer_check_enabled(&self) -> bool;
+
+    /// Calls `PSP22Receiver::before_received` of the recipient `to`.
+    ///
+    /// Accounts that are not contracts and contracts that don't implement `PSP22Receiver` accept tokens.
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: Option<&AccountId>,
+        to: &AccountId,
+        value: &Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error>;
+
     fn _after_token_transfer(
         &mut self,
         _from: Option<&AccountId>,
@@ -180,7 +199,7 @@ pub trait InternalImpl: Storage<Data> + Internal {
         from: AccountId,
         to: AccountId,
         amount: Balance,
-        _data: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<(), PSP22Error> {
         let from_balance = Internal::_balance_of(self, &from);
 
@@ -189,6 +208,9 @@ pub trait InternalImpl: Storage<Data> + Internal {
         }
 
         Internal::_before_token_transfer(self, Some(&from), Some(&to), &amount)?;
+        if Internal::_is_safe_transfer_check_enabled(self) {
+            Internal::_do_safe_transfer_check(self, Some(&from), &to, &amount, data)?;
+        }
 
         self.data().balances.insert(&from, &(from_balance - amount));
 
@@ -209,6 +231,9 @@ pub trait InternalImpl: Storage<Data> + Internal {
 
     fn _mint_to(&mut self, account: AccountId, amount: Balance) -> Result<(), PSP22Error> {
         Internal::_before_token_transfer(self, None, Some(&account), &amount)?;
+        if Internal::_is_safe_transfer_check_enabled(self) {
+            Internal::_do_safe_transfer_check(self, None, &account, &amount, Vec::new())?;
+        }
         let mut new_balance = Internal::_balance_of(self, &account);
         new_balance += amount;
         self.data().balances.insert(&account, &new_balance);
@@ -260,4 +285,31 @@ pub trait InternalImpl: Storage<Data> + Internal {
     ) -> Result<(), PSP22Error> {
         Ok(())
     }
+
+    fn _is_safe_transfer_check_enabled(&self) -> bool {
+        false
+    }
+
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: Option<&AccountId>,
+        to: &AccountId,
+        value: &Balance,
+        data: Vec<u8>,
+    ) -> Result<(), PSP22Error> {
+        // The same as `NotCallable`, but without the cross-contract call
+        if !Self::env().is_contract(to) {
+            return Ok(())
+        }
+        let builder = PSP22ReceiverRef::before_received_builder(to, Self::env().caller(), from.copied(), *value, data)
+            .call_flags(CallFlags::default().set_allow_reentry(true));
+        safe_transfer_check_result(builder.try_invoke())
+    }
+}
+
+/// Converts the result of the `PSP22Receiver::before_received` call into the result of the safe transfer check.
+pub fn safe_transfer_check_result(
+    result: ink::env::Result<ink::MessageResult<Result<(), PSP22ReceiverError>>>,
+) -> Result<(), PSP22Error> {
+    crate::traits::errors::safe_transfer_check_result(result, PSP22Error::SafeTransferCheckFailed)
 }
@@ -28,6 +28,8 @@ pub use crate::{
         *,
     },
 };
+use core::cmp::min;
+use ink::prelude::vec::Vec;
 use openbrush::{
     storage::{
         Mapping,
@@ -57,6 +59,9 @@ pub struct Data {
     pub balances: MultiMapping<Option<AccountId>, Id, EnumerableKey>,
 }
 
+/// Maximum number of token ids returned by one page of `tokens_of_owner` and `tokens`.
+pub const MAX_PAGE_SIZE: u128 = 100;
+
 pub struct EnumerableKey;
 
 impl<'a> TypeGuard<'a> for EnumerableKey {
@@ -125,4 +130,25 @@ pub trait PSP34EnumerableImpl: Storage<Data> {
             .get_value(&None, &index)
             .ok_or(PSP34Error::TokenNotExists)
     }
+
+    fn tokens_of_owner(&self, owner: AccountId, start: u128, limit: u128) -> Vec<Id> {
+        let balances = &self.data().balances;
+        let end = min(
+            start.saturating_add(min(limit, MAX_PAGE_SIZE)),
+            balances.count(&Some(&owner)),
+        );
+
+        (start..end)
+            .filter_map(|index| balances.get_value(&Some(&owner), &index))
+            .collect()
+    }
+
+    fn tokens(&self, start: u128, limit: u128) -> Vec<Id> {
+        let balances = &self.data().balances;
+        let end = min(start.saturating_add(min(limit, MAX_PAGE_SIZE)), balances.count(&None));
+
+        (start..end)
+            .filter_map(|index| balances.get_value(&None, &index))
+            .collect()
+    }
 }
@@ -27,6 +27,7 @@ pub use crate::{
         *,
     },
 };
+use ink::prelude::string::ToString;
 pub use metadata::Internal as _;
 pub use openbrush::traits::String;
 use openbrush::{
@@ -49,6 +50,9 @@ pub use psp34::{
 #[openbrush::storage_item]
 pub struct Data {
     pub attributes: Mapping<(Id, String), String, AttributesKey>,
+    #[lazy]
+    pub base_uri: Option<String>,
+    pub token_uris: Mapping<Id, String>,
 }
 
 pub struct AttributesKey;
@@ -57,10 +61,27 @@ impl<'a> TypeGuard<'a> for AttributesKey {
     type Type = &'a (&'a Id, &'a String);
 }
 
-pub trait PSP34MetadataImpl: Storage<Data> {
+pub trait PSP34MetadataImpl: Storage<Data> + psp34::Internal {
     fn get_attribute(&self, id: Id, key: String) -> Option<String> {
         self.data().attributes.get(&(&id, &key))
     }
+
+    fn base_uri(&self) -> Option<String> {
+        self.data().base_uri.get_or_default()
+    }
+
+    fn token_uri(&self, id: Id) -> Option<String> {
+        psp34::Internal::_owner_of(self, &id)?;
+
+        if let Some(uri) = self.data().token_uris.get(&id) {
+            return Some(uri)
+        }
+
+        self.data()
+            .base_uri
+            .get_or_default()
+            .map(|base_uri| base_uri + &id_to_string(&id))
+    }
 }
 
 pub trait Internal {
@@ -68,6 +89,15 @@ pub trait Internal {
     fn _emit_attribute_set_event(&self, id: Id, key: String, data: String);
 
     fn _set_attribute(&mut self, id: Id, key: String, value: String);
+
+    /// Sets the base URI to which the rendered token ids are appended.
+    fn _set_base_uri(&mut self, uri: String);
+
+    /// Sets the URI of the token `id`, it overrides the URI built from the base URI.
+    fn _set_token_uri(&mut self, id: Id, uri: String);
+
+    /// Removes the URI of the token `id`, the URI built from the base URI is used afterwards.
+    fn _remove_token_uri(&mut self, id: Id);
 }
 
 pub trait InternalImpl: Internal + Storage<Data> {
@@ -77,4 +107,39 @@ pub trait InternalImpl: Internal + Storage<Data> {
         self.data().attributes.insert(&(&id, &key), &value);
         Internal::_emit_attribute_set_event(self, id, key, value);
     }
+
+    fn _set_base_uri(&mut self, uri: String) {
+        self.data().base_uri.set(&Some(uri));
+    }
+
+    fn _set_token_uri(&mut self, id: Id, uri: String) {
+        self.data().token_uris.insert(&id, &uri);
+    }
+
+    fn _remove_token_uri(&mut self, id: Id) {
+        self.data().token_uris.remove(&id);
+    }
+}
+
+/// Renders the `id` as it is used in the token URI.
+///
+/// Number ids are rendered in decimal and `Id::Bytes` in lowercase hex without prefix.
+pub fn id_to_string(id: &Id) -> String {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+
+    match id {
+        Id::U8(n) => n.to_string(),
+        Id::U16(n) => n.to_string(),
+        Id::U32(n) => n.to_string(),
+        Id::U64(n) => n.to_string(),
+        Id::U128(n) => n.to_string(),
+        Id::Bytes(bytes) => {
+            let mut rendered = String::with_capacity(bytes.len() * 2);
+            for byte in bytes {
+                rendered.push(HEX[(byte >> 4) as usize] as char);
+                rendered.push(HEX[(byte & 0xf) as usize] as char);
+            }
+            rendered
+        }
+    }
 }
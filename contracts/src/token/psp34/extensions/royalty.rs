@@ -0,0 +1,181 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::{
+    psp34,
+    psp34::extensions::royalty,
+    traits::psp34::{
+        extensions::royalty::*,
+        *,
+    },
+};
+pub use openbrush::traits::String;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+pub use psp34::{
+    BalancesManager as _,
+    Internal as _,
+    InternalImpl as _,
+    Operator,
+    Owner,
+    PSP34Impl,
+};
+pub use royalty::Internal as _;
+
+/// Default fee denominator, the royalty fraction is expressed in basis points.
+pub const FEE_DENOMINATOR: u128 = 10_000;
+
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct Data {
+    #[lazy]
+    pub default_royalty: Option<RoyaltyInfo>,
+    pub token_royalty: Mapping<Id, RoyaltyInfo>,
+}
+
+pub trait PSP34RoyaltyImpl: Internal {
+    fn royalty_info(&self, id: Id, sale_price: Balance) -> (AccountId, Balance) {
+        self._royalty_info(&id, sale_price)
+    }
+}
+
+pub trait Internal {
+    /// Returns the denominator with which the royalty fraction is interpreted.
+    fn _fee_denominator(&self) -> u128;
+
+    fn _royalty_info(&self, id: &Id, sale_price: Balance) -> (AccountId, Balance);
+
+    /// Sets the royalty that is used for all tokens without their own royalty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RoyaltyFeeExceedsSalePrice` error if `fee_numerator` is greater than `_fee_denominator`.
+    ///
+    /// Returns `RoyaltyReceiverIsZero` error if `receiver` is the zero address.
+    fn _set_default_royalty(&mut self, receiver: AccountId, fee_numerator: u128) -> Result<(), PSP34Error>;
+
+    fn _delete_default_royalty(&mut self);
+
+    /// Sets the royalty of the token `id`, it overrides the default royalty.
+    ///
+    /// # Errors
+    ///
+    /// Returns `RoyaltyFeeExceedsSalePrice` error if `fee_numerator` is greater than `_fee_denominator`.
+    ///
+    /// Returns `RoyaltyReceiverIsZero` error if `receiver` is the zero address.
+    fn _set_token_royalty(&mut self, id: &Id, receiver: AccountId, fee_numerator: u128) -> Result<(), PSP34Error>;
+
+    /// Resets the royalty of the token `id` back to the default royalty.
+    fn _reset_token_royalty(&mut self, id: &Id);
+
+    /// Clears the royalty of the token `id` when it is burned.
+    fn _clear_burned_token_royalty(
+        &mut self,
+        from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error>;
+}
+
+pub trait InternalImpl: Internal + Storage<Data> {
+    fn _fee_denominator(&self) -> u128 {
+        FEE_DENOMINATOR
+    }
+
+    fn _royalty_info(&self, id: &Id, sale_price: Balance) -> (AccountId, Balance) {
+        let royalty = match self.data().token_royalty.get(id) {
+            Some(royalty) => royalty,
+            None => {
+                match self.data().default_royalty.get_or_default() {
+                    Some(royalty) => royalty,
+                    None => return (AccountId::from([0x0; 32]), 0),
+                }
+            }
+        };
+        let denominator = Internal::_fee_denominator(self);
+
+        // `sale_price * royalty_fraction / denominator` without overflow of the multiplication,
+        // `royalty_fraction` is never greater than `denominator`
+        let amount = (sale_price / denominator) * royalty.royalty_fraction
+            + (sale_price % denominator) * royalty.royalty_fraction / denominator;
+
+        (royalty.receiver, amount)
+    }
+
+    fn _set_default_royalty(&mut self, receiver: AccountId, fee_numerator: u128) -> Result<(), PSP34Error> {
+        if fee_numerator > Internal::_fee_denominator(self) {
+            return Err(PSP34Error::RoyaltyFeeExceedsSalePrice)
+        }
+        if receiver == AccountId::from([0x0; 32]) {
+            return Err(PSP34Error::RoyaltyReceiverIsZero)
+        }
+
+        self.data().default_royalty.set(&Some(RoyaltyInfo {
+            receiver,
+            royalty_fraction: fee_numerator,
+        }));
+        Ok(())
+    }
+
+    fn _delete_default_royalty(&mut self) {
+        self.data().default_royalty.set(&None);
+    }
+
+    fn _set_token_royalty(&mut self, id: &Id, receiver: AccountId, fee_numerator: u128) -> Result<(), PSP34Error> {
+        if fee_numerator > Internal::_fee_denominator(self) {
+            return Err(PSP34Error::RoyaltyFeeExceedsSalePrice)
+        }
+        if receiver == AccountId::from([0x0; 32]) {
+            return Err(PSP34Error::RoyaltyReceiverIsZero)
+        }
+
+        self.data().token_royalty.insert(
+            id,
+            &RoyaltyInfo {
+                receiver,
+                royalty_fraction: fee_numerator,
+            },
+        );
+        Ok(())
+    }
+
+    fn _reset_token_royalty(&mut self, id: &Id) {
+        self.data().token_royalty.remove(id);
+    }
+
+    fn _clear_burned_token_royalty(
+        &mut self,
+        _from: Option<&AccountId>,
+        to: Option<&AccountId>,
+        id: &Id,
+    ) -> Result<(), PSP34Error> {
+        if to.is_none() {
+            Internal::_reset_token_royalty(self, id);
+        }
+        Ok(())
+    }
+}
@@ -29,6 +29,7 @@ pub mod extensions {
     pub mod enumerable;
     pub mod metadata;
     pub mod mintable;
+    pub mod royalty;
 }
 
 pub type Owner = AccountId;
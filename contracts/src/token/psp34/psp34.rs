@@ -27,7 +27,10 @@ pub use crate::{
     },
     traits::psp34::*,
 };
-use ink::prelude::vec::Vec;
+use ink::{
+    env::CallFlags,
+    prelude::vec::Vec,
+};
 use openbrush::{
     storage::{
         Mapping,
@@ -130,6 +133,17 @@ pub trait Internal {
         to: Option<&AccountId>,
         id: &Id,
     ) -> Result<(), PSP34Error>;
+
+    /// Calls `PSP34Receiver::before_received` of the recipient `to`, it is called on every transfer and mint.
+    ///
+    /// Accounts that are not contracts and contracts that don't implement `PSP34Receiver` accept tokens.
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: Option<&AccountId>,
+        to: &AccountId,
+        id: &Id,
+        data: Vec<u8>,
+    ) -> Result<(), PSP34Error>;
 }
 
 pub trait InternalImpl: Internal + BalancesManager + Sized {
@@ -167,7 +181,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         BalancesManager::_owner_of(self, id)
     }
 
-    fn _transfer_token(&mut self, to: AccountId, id: Id, _data: Vec<u8>) -> Result<(), PSP34Error> {
+    fn _transfer_token(&mut self, to: AccountId, id: Id, data: Vec<u8>) -> Result<(), PSP34Error> {
         let owner = Internal::_check_token_exists(self, &id)?;
         let caller = Self::env().caller();
 
@@ -176,6 +190,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         }
 
         Internal::_before_token_transfer(self, Some(&owner), Some(&to), &id)?;
+        Internal::_do_safe_transfer_check(self, Some(&owner), &to, &id, data)?;
 
         self._remove_operator_approvals(&owner, &caller, &Some(&id));
         BalancesManager::_decrease_balance(self, &owner, &id, false);
@@ -194,6 +209,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
             return Err(PSP34Error::TokenExists)
         }
         Internal::_before_token_transfer(self, None, Some(&to), &id)?;
+        Internal::_do_safe_transfer_check(self, None, &to, &id, Vec::new())?;
 
         BalancesManager::_increase_balance(self, &to, &id, true);
         self._insert_token_owner(&id, &to);
@@ -241,6 +257,30 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
     ) -> Result<(), PSP34Error> {
         Ok(())
     }
+
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: Option<&AccountId>,
+        to: &AccountId,
+        id: &Id,
+        data: Vec<u8>,
+    ) -> Result<(), PSP34Error> {
+        // The same as `NotCallable`, but without the cross-contract call
+        if !Self::env().is_contract(to) {
+            return Ok(())
+        }
+        let builder =
+            PSP34ReceiverRef::before_received_builder(to, Self::env().caller(), from.copied(), id.clone(), data)
+                .call_flags(CallFlags::default().set_allow_reentry(true));
+        safe_transfer_check_result(builder.try_invoke())
+    }
+}
+
+/// Converts the result of the `PSP34Receiver::before_received` call into the result of the safe transfer check.
+pub fn safe_transfer_check_result(
+    result: ink::env::Result<ink::MessageResult<Result<(), PSP34ReceiverError>>>,
+) -> Result<(), PSP34Error> {
+    crate::traits::errors::safe_transfer_check_result(result, PSP34Error::SafeTransferCheckFailed)
 }
 
 pub trait BalancesManager {
@@ -79,7 +79,7 @@ pub trait InternalImpl: Internal + psp37::Internal + Storage<psp37::Data> + psp3
         from: AccountId,
         to: AccountId,
         ids_amounts: Vec<(Id, Balance)>,
-        _: Vec<u8>,
+        data: Vec<u8>,
     ) -> Result<(), PSP37Error> {
         let operator = Self::env().caller();
 
@@ -90,6 +90,7 @@ pub trait InternalImpl: Internal + psp37::Internal + Storage<psp37::Data> + psp3
         }
 
         self._before_token_transfer(Some(&from), Some(&to), &ids_amounts)?;
+        self._do_safe_transfer_check(Some(&from), &to, &ids_amounts, data)?;
 
         for (id, value) in &ids_amounts {
             self._decrease_allowance(&from, &operator, id, *value)?;
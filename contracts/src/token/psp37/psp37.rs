@@ -24,9 +24,12 @@ pub use crate::{
     traits::psp37::*,
 };
 use core::result::Result;
-use ink::prelude::{
-    vec,
-    vec::Vec,
+use ink::{
+    env::CallFlags,
+    prelude::{
+        vec,
+        vec::Vec,
+    },
 };
 use openbrush::{
     storage::{
@@ -185,6 +188,18 @@ pub trait Internal {
         to: Option<&AccountId>,
         ids: &[(Id, Balance)],
     ) -> Result<(), PSP37Error>;
+
+    /// Calls `PSP37Receiver::before_received` of the recipient `to` with all transferred `ids_amounts`,
+    /// it is called on every transfer, batch transfer and mint.
+    ///
+    /// Accounts that are not contracts and contracts that don't implement `PSP37Receiver` accept tokens.
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: Option<&AccountId>,
+        to: &AccountId,
+        ids_amounts: &[(Id, Balance)],
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error>;
 }
 
 pub trait InternalImpl: Internal + BalancesManager + Sized {
@@ -206,6 +221,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         }
 
         Internal::_before_token_transfer(self, None, Some(&to), &ids_amounts)?;
+        Internal::_do_safe_transfer_check(self, None, &to, &ids_amounts, Vec::new())?;
 
         for (id, amount) in &ids_amounts {
             self._increase_balance(&to, id, amount, true)?;
@@ -262,6 +278,7 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
         }
 
         Internal::_before_token_transfer(self, Some(&from), Some(&to), &ids_amounts)?;
+        Internal::_do_safe_transfer_check(self, Some(&from), &to, &ids_amounts, data.clone())?;
         Internal::_decrease_allowance(self, &from, &operator, &id, value)?;
         Internal::_transfer_token(self, &from, &to, id.clone(), value, &data)?;
         Internal::_after_token_transfer(self, Some(&from), Some(&to), &ids_amounts)?;
@@ -356,6 +373,35 @@ pub trait InternalImpl: Internal + BalancesManager + Sized {
     ) -> Result<(), PSP37Error> {
         Ok(())
     }
+
+    fn _do_safe_transfer_check(
+        &mut self,
+        from: Option<&AccountId>,
+        to: &AccountId,
+        ids_amounts: &[(Id, Balance)],
+        data: Vec<u8>,
+    ) -> Result<(), PSP37Error> {
+        // The same as `NotCallable`, but without the cross-contract call
+        if !Self::env().is_contract(to) {
+            return Ok(())
+        }
+        let builder = PSP37ReceiverRef::before_received_builder(
+            to,
+            Self::env().caller(),
+            from.copied(),
+            ids_amounts.to_vec(),
+            data,
+        )
+        .call_flags(CallFlags::default().set_allow_reentry(true));
+        safe_transfer_check_result(builder.try_invoke())
+    }
+}
+
+/// Converts the result of the `PSP37Receiver::before_received` call into the result of the safe transfer check.
+pub fn safe_transfer_check_result(
+    result: ink::env::Result<ink::MessageResult<Result<(), PSP37ReceiverError>>>,
+) -> Result<(), PSP37Error> {
+    crate::traits::errors::safe_transfer_check_result(result, PSP37Error::SafeTransferCheckFailed)
 }
 
 pub trait BalancesManager {
@@ -20,7 +20,10 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub use crate::traits::errors::AccessControlError;
-use openbrush::traits::AccountId;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
 
 pub type RoleType = u32;
 
@@ -55,6 +58,29 @@ pub trait AccessControl {
     #[ink(message)]
     fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError>;
 
+    /// Grants `role` to `account` until the `expiry` timestamp.
+    /// After `expiry` the `account` doesn't have the `role` anymore.
+    ///
+    /// On success a `RoleGranted` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller can't grant the role.
+    /// Returns with `RoleRedundant` error `account` has `role`.
+    /// Returns with `RoleExpired` error if `expiry` is not in the future.
+    #[ink(message)]
+    fn grant_role_with_expiry(
+        &mut self,
+        role: RoleType,
+        account: Option<AccountId>,
+        expiry: Timestamp,
+    ) -> Result<(), AccessControlError>;
+
+    /// Returns the timestamp when `role` of `account` expires, even if it has already expired.
+    /// Returns `None` if the `role` was granted without expiry or `account` was not granted `role`.
+    #[ink(message)]
+    fn role_expiry(&self, role: RoleType, account: Option<AccountId>) -> Option<Timestamp>;
+
     /// Revokes `role` from `account`.
     ///
     /// On success a `RoleRevoked` event is emitted.
@@ -0,0 +1,94 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::access_control::*;
+use openbrush::traits::{
+    AccountId,
+    Timestamp,
+};
+
+#[openbrush::wrapper]
+pub type AccessControlDefaultAdminRulesRef = dyn AccessControlDefaultAdminRules;
+
+/// Extension of AccessControl that enforces additional security rules for the default admin role.
+///
+/// There is only one holder of the default admin role at any time, and it can't be granted
+/// or revoked via `grant_role` and `revoke_role`. The default admin is transferred in two steps:
+/// the current admin begins the transfer, and the new admin accepts it after the delay.
+/// The pending transfer can be cancelled by the current admin at any time before it is accepted.
+#[openbrush::trait_definition]
+pub trait AccessControlDefaultAdminRules {
+    /// Returns the address of the current default admin.
+    #[ink(message)]
+    fn default_admin(&self) -> Option<AccountId>;
+
+    /// Returns the pending default admin and the timestamp after which it can accept the role.
+    ///
+    /// A zero timestamp means that there is no pending transfer.
+    #[ink(message)]
+    fn pending_default_admin(&self) -> (Option<AccountId>, Timestamp);
+
+    /// Returns the delay between the beginning and the acceptance of the default admin transfer.
+    #[ink(message)]
+    fn default_admin_delay(&self) -> Timestamp;
+
+    /// Begins the transfer of the default admin role to `new_admin`, replacing the pending transfer.
+    /// `None` schedules the renouncing of the role by the current default admin.
+    ///
+    /// On success a `DefaultAdminTransferScheduled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller is not the default admin.
+    #[ink(message)]
+    fn begin_default_admin_transfer(&mut self, new_admin: Option<AccountId>) -> Result<(), AccessControlError>;
+
+    /// Cancels the pending transfer of the default admin role.
+    ///
+    /// On success a `DefaultAdminTransferCanceled` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller is not the default admin.
+    #[ink(message)]
+    fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError>;
+
+    /// The pending default admin accepts the default admin role.
+    ///
+    /// On success `RoleRevoked` and `RoleGranted` events are emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `InvalidCaller` error if caller is not the pending default admin.
+    /// Returns with `DefaultAdminTransferNotReady` error if the delay has not passed yet.
+    #[ink(message)]
+    fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError>;
+
+    /// Changes the delay of the default admin transfer. It doesn't affect the pending transfer.
+    ///
+    /// On success a `DefaultAdminDelayChanged` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `MissingRole` error if caller is not the default admin.
+    #[ink(message)]
+    fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError>;
+}
@@ -32,12 +32,17 @@ pub trait AccessControlEnumerable {
     ///
     /// Role bearers are not sorted in any particular way, and their
     /// ordering may change at any point.
+    ///
+    /// Members whose role has expired are still returned until the role is revoked,
+    /// use `AccessControl::has_role` to check that the role is active.
     #[ink(message)]
     fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId>;
 
     /// Returns the number of accounts that have `role`.
     /// Can be used together with {get_role_member} to enumerate
     /// all bearers of a role.
+    ///
+    /// Members whose role has expired are counted until the role is revoked.
     #[ink(message)]
     fn get_role_member_count(&self, role: RoleType) -> u32;
 }
@@ -20,11 +20,14 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 /// The AccessControl error type. Contract will throw one of this errors.
-/// Modifier returns `MissingRole`.
+/// Modifier returns `MissingRole` or `RoleExpired`.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub enum AccessControlError {
     InvalidCaller,
     MissingRole,
     RoleRedundant,
+    EnforcedDefaultAdminRules,
+    DefaultAdminTransferNotReady,
+    RoleExpired,
 }
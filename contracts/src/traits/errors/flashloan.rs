@@ -42,6 +42,9 @@ impl From<OwnableError> for FlashBorrowerError {
                 FlashBorrowerError::FlashloanRejected(String::from("O::CallerIsNotOwner"))
             }
             OwnableError::NewOwnerIsZero => FlashBorrowerError::FlashloanRejected(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                FlashBorrowerError::FlashloanRejected(String::from("O::CallerIsNotPendingOwner"))
+            }
         }
     }
 }
@@ -56,6 +59,13 @@ impl From<AccessControlError> for FlashBorrowerError {
             AccessControlError::InvalidCaller => {
                 FlashBorrowerError::FlashloanRejected(String::from("AC::InvalidCaller"))
             }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                FlashBorrowerError::FlashloanRejected(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                FlashBorrowerError::FlashloanRejected(String::from("AC::DefaultAdminTransferNotReady"))
+            }
+            AccessControlError::RoleExpired => FlashBorrowerError::FlashloanRejected(String::from("AC::RoleExpired")),
         }
     }
 }
@@ -110,6 +120,10 @@ impl From<PSP22Error> for FlashLenderError {
             PSP22Error::ZeroRecipientAddress => FlashLenderError::Custom(String::from("PSP22: Zero Recipient Address")),
             PSP22Error::ZeroSenderAddress => FlashLenderError::Custom(String::from("PSP22: Zero Sender Address")),
             PSP22Error::SafeTransferCheckFailed(message) => FlashLenderError::Custom(message),
+            PSP22Error::PermitExpired => FlashLenderError::Custom(String::from("PSP22: Permit Expired")),
+            PSP22Error::PermitInvalidSignature => {
+                FlashLenderError::Custom(String::from("PSP22: Permit Invalid Signature"))
+            }
         }
     }
 }
@@ -127,6 +141,9 @@ impl From<OwnableError> for FlashLenderError {
         match ownable {
             OwnableError::CallerIsNotOwner => FlashLenderError::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => FlashLenderError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                FlashLenderError::Custom(String::from("O::CallerIsNotPendingOwner"))
+            }
         }
     }
 }
@@ -137,6 +154,13 @@ impl From<AccessControlError> for FlashLenderError {
             AccessControlError::MissingRole => FlashLenderError::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => FlashLenderError::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => FlashLenderError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::EnforcedDefaultAdminRules => {
+                FlashLenderError::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                FlashLenderError::Custom(String::from("AC::DefaultAdminTransferNotReady"))
+            }
+            AccessControlError::RoleExpired => FlashLenderError::Custom(String::from("AC::RoleExpired")),
         }
     }
 }
@@ -0,0 +1,117 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::{
+    AccessControlError,
+    OwnableError,
+    PSP22Error,
+    PausableError,
+    ReentrancyGuardError,
+    TimelockControllerError,
+};
+use openbrush::traits::String;
+
+/// The Governor error type. Contract will throw one of this errors.
+#[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum GovernorError {
+    Custom(String),
+    TimelockControllerError(TimelockControllerError),
+    PSP22Error(PSP22Error),
+    /// Returned if the proposal doesn't contain any transaction
+    EmptyProposal,
+    /// Returned if the proposal with the same id was already created
+    ProposalAlreadyExists,
+    /// Returned if the proposal doesn't exist
+    ProposalNotFound,
+    /// Returned if the proposal is not in the state required by the operation
+    UnexpectedProposalState,
+    /// Returned if the proposer doesn't have enough votes to create a proposal
+    InsufficientProposerVotes,
+    /// Returned if the voter already voted for the proposal
+    AlreadyCastVote,
+    /// Returned if the caller is not the proposer of the proposal
+    OnlyProposer,
+    /// Returned if the caller is not the timelock that executes proposals
+    OnlyGovernance,
+    /// Returned if the quorum numerator is bigger than the denominator
+    InvalidQuorumFraction,
+    /// Returned if the voting period is zero
+    InvalidVotingPeriod,
+    /// Returned if the token or timelock are not initialized
+    NotInitialized,
+}
+
+impl From<TimelockControllerError> for GovernorError {
+    fn from(error: TimelockControllerError) -> Self {
+        GovernorError::TimelockControllerError(error)
+    }
+}
+
+impl From<PSP22Error> for GovernorError {
+    fn from(error: PSP22Error) -> Self {
+        GovernorError::PSP22Error(error)
+    }
+}
+
+impl From<AccessControlError> for GovernorError {
+    fn from(access: AccessControlError) -> Self {
+        match access {
+            AccessControlError::MissingRole => GovernorError::Custom(String::from("AC::MissingRole")),
+            AccessControlError::RoleRedundant => GovernorError::Custom(String::from("AC::RoleRedundant")),
+            AccessControlError::InvalidCaller => GovernorError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::EnforcedDefaultAdminRules => {
+                GovernorError::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                GovernorError::Custom(String::from("AC::DefaultAdminTransferNotReady"))
+            }
+            AccessControlError::RoleExpired => GovernorError::Custom(String::from("AC::RoleExpired")),
+        }
+    }
+}
+
+impl From<OwnableError> for GovernorError {
+    fn from(ownable: OwnableError) -> Self {
+        match ownable {
+            OwnableError::CallerIsNotOwner => GovernorError::Custom(String::from("O::CallerIsNotOwner")),
+            OwnableError::NewOwnerIsZero => GovernorError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => GovernorError::Custom(String::from("O::CallerIsNotPendingOwner")),
+        }
+    }
+}
+
+impl From<PausableError> for GovernorError {
+    fn from(pausable: PausableError) -> Self {
+        match pausable {
+            PausableError::Paused => GovernorError::Custom(String::from("P::Paused")),
+            PausableError::NotPaused => GovernorError::Custom(String::from("P::NotPaused")),
+        }
+    }
+}
+
+impl From<ReentrancyGuardError> for GovernorError {
+    fn from(guard: ReentrancyGuardError) -> Self {
+        match guard {
+            ReentrancyGuardError::ReentrantCall => GovernorError::Custom(String::from("RG::ReentrantCall")),
+        }
+    }
+}
@@ -22,6 +22,7 @@
 mod access_control;
 mod diamond;
 mod flashloan;
+mod governor;
 mod ownable;
 mod pausable;
 mod payment_splitter;
@@ -29,6 +30,7 @@ mod psp22;
 mod psp34;
 mod psp37;
 mod reentrancy_guard;
+mod safe_transfer;
 mod timelock_controller;
 mod upgradeable;
 
@@ -38,6 +40,7 @@ pub use flashloan::{
     FlashBorrowerError,
     FlashLenderError,
 };
+pub use governor::GovernorError;
 pub use ownable::OwnableError;
 pub use pausable::PausableError;
 pub use payment_splitter::PaymentSplitterError;
@@ -55,5 +58,6 @@ pub use psp37::{
     PSP37ReceiverError,
 };
 pub use reentrancy_guard::ReentrancyGuardError;
+pub use safe_transfer::safe_transfer_check_result;
 pub use timelock_controller::TimelockControllerError;
 pub use upgradeable::UpgradeableError;
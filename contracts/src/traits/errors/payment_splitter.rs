@@ -25,7 +25,10 @@ use super::{
     PausableError,
     ReentrancyGuardError,
 };
-use openbrush::traits::String;
+use openbrush::traits::{
+    AccountId,
+    String,
+};
 
 /// The PaymentSplitter error type. Contract will throw one of this errors.
 #[derive(Debug, PartialEq, Eq, scale::Encode, scale::Decode)]
@@ -39,6 +42,10 @@ pub enum PaymentSplitterError {
     SharesAreZero,
     AlreadyHasShares,
     TransferFailed,
+    TooManyPayees,
+    TooManyTokens,
+    TokenIsNotTracked,
+    TokenBalanceFailed(AccountId),
 }
 
 impl From<AccessControlError> for PaymentSplitterError {
@@ -47,6 +54,13 @@ impl From<AccessControlError> for PaymentSplitterError {
             AccessControlError::MissingRole => PaymentSplitterError::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PaymentSplitterError::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PaymentSplitterError::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PaymentSplitterError::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                PaymentSplitterError::Custom(String::from("AC::DefaultAdminTransferNotReady"))
+            }
+            AccessControlError::RoleExpired => PaymentSplitterError::Custom(String::from("AC::RoleExpired")),
         }
     }
 }
@@ -56,6 +70,9 @@ impl From<OwnableError> for PaymentSplitterError {
         match ownable {
             OwnableError::CallerIsNotOwner => PaymentSplitterError::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PaymentSplitterError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                PaymentSplitterError::Custom(String::from("O::CallerIsNotPendingOwner"))
+            }
         }
     }
 }
@@ -43,6 +43,10 @@ pub enum PSP22Error {
     ZeroSenderAddress,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if the deadline of the permit is in the past.
+    PermitExpired,
+    /// Returned if the signature of the permit is not made by the owner.
+    PermitInvalidSignature,
 }
 
 impl From<OwnableError> for PSP22Error {
@@ -50,6 +54,7 @@ impl From<OwnableError> for PSP22Error {
         match ownable {
             OwnableError::CallerIsNotOwner => PSP22Error::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PSP22Error::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => PSP22Error::Custom(String::from("O::CallerIsNotPendingOwner")),
         }
     }
 }
@@ -60,6 +65,13 @@ impl From<AccessControlError> for PSP22Error {
             AccessControlError::MissingRole => PSP22Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP22Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP22Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PSP22Error::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                PSP22Error::Custom(String::from("AC::DefaultAdminTransferNotReady"))
+            }
+            AccessControlError::RoleExpired => PSP22Error::Custom(String::from("AC::RoleExpired")),
         }
     }
 }
@@ -122,6 +134,10 @@ impl From<PSP22Error> for PSP22TokenTimelockError {
             PSP22Error::SafeTransferCheckFailed(message) => {
                 PSP22TokenTimelockError::PSP22Error(PSP22Error::SafeTransferCheckFailed(message))
             }
+            PSP22Error::PermitExpired => PSP22TokenTimelockError::PSP22Error(PSP22Error::PermitExpired),
+            PSP22Error::PermitInvalidSignature => {
+                PSP22TokenTimelockError::PSP22Error(PSP22Error::PermitInvalidSignature)
+            }
         }
     }
 }
@@ -43,6 +43,10 @@ pub enum PSP34Error {
     TokenNotExists,
     /// Returned if safe transfer check fails
     SafeTransferCheckFailed(String),
+    /// Returned if the royalty fee is greater than the fee denominator
+    RoyaltyFeeExceedsSalePrice,
+    /// Returned if the royalty receiver is the zero address
+    RoyaltyReceiverIsZero,
 }
 
 impl From<OwnableError> for PSP34Error {
@@ -50,6 +54,7 @@ impl From<OwnableError> for PSP34Error {
         match ownable {
             OwnableError::CallerIsNotOwner => PSP34Error::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PSP34Error::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => PSP34Error::Custom(String::from("O::CallerIsNotPendingOwner")),
         }
     }
 }
@@ -60,6 +65,13 @@ impl From<AccessControlError> for PSP34Error {
             AccessControlError::MissingRole => PSP34Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP34Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP34Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PSP34Error::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                PSP34Error::Custom(String::from("AC::DefaultAdminTransferNotReady"))
+            }
+            AccessControlError::RoleExpired => PSP34Error::Custom(String::from("AC::RoleExpired")),
         }
     }
 }
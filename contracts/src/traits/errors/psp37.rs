@@ -52,6 +52,7 @@ impl From<OwnableError> for PSP37Error {
         match ownable {
             OwnableError::CallerIsNotOwner => PSP37Error::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => PSP37Error::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => PSP37Error::Custom(String::from("O::CallerIsNotPendingOwner")),
         }
     }
 }
@@ -62,6 +63,13 @@ impl From<AccessControlError> for PSP37Error {
             AccessControlError::MissingRole => PSP37Error::Custom(String::from("AC::MissingRole")),
             AccessControlError::RoleRedundant => PSP37Error::Custom(String::from("AC::RoleRedundant")),
             AccessControlError::InvalidCaller => PSP37Error::Custom(String::from("AC::InvalidCaller")),
+            AccessControlError::EnforcedDefaultAdminRules => {
+                PSP37Error::Custom(String::from("AC::EnforcedDefaultAdminRules"))
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                PSP37Error::Custom(String::from("AC::DefaultAdminTransferNotReady"))
+            }
+            AccessControlError::RoleExpired => PSP37Error::Custom(String::from("AC::RoleExpired")),
         }
     }
 }
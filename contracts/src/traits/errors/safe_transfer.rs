@@ -0,0 +1,40 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use openbrush::traits::String;
+
+/// Converts the result of the `before_received` call of the token receiver into the result
+/// of the safe transfer check. The errors of the receiver are converted into `E`, and `failed`
+/// creates the error of the failed call, for example, `PSP22Error::SafeTransferCheckFailed`.
+pub fn safe_transfer_check_result<R, E: From<R>>(
+    result: ink::env::Result<ink::MessageResult<Result<(), R>>>,
+    failed: fn(String) -> E,
+) -> Result<(), E> {
+    match result {
+        Ok(Ok(Ok(_))) => Ok(()),
+        Ok(Ok(Err(e))) => Err(e.into()),
+        // Means unknown method
+        Ok(Err(ink::LangError::CouldNotReadInput)) => Ok(()),
+        // `NotCallable` means that the receiver is not a contract.
+        Err(ink::env::Error::NotCallable) => Ok(()),
+        _ => Err(failed(String::from("Error while performing the `before_received`"))),
+    }
+}
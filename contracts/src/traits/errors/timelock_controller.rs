@@ -41,6 +41,9 @@ pub enum TimelockControllerError {
     UnderlyingTransactionReverted,
     CallerMustBeTimeLock,
     CalleeZeroAddress,
+    OperationExpired,
+    InsufficientBalance,
+    TransferFailed,
 }
 
 impl From<AccessControlError> for TimelockControllerError {
@@ -55,6 +58,15 @@ impl From<AccessControlError> for TimelockControllerError {
             AccessControlError::InvalidCaller => {
                 TimelockControllerError::AccessControlError(AccessControlError::InvalidCaller)
             }
+            AccessControlError::EnforcedDefaultAdminRules => {
+                TimelockControllerError::AccessControlError(AccessControlError::EnforcedDefaultAdminRules)
+            }
+            AccessControlError::DefaultAdminTransferNotReady => {
+                TimelockControllerError::AccessControlError(AccessControlError::DefaultAdminTransferNotReady)
+            }
+            AccessControlError::RoleExpired => {
+                TimelockControllerError::AccessControlError(AccessControlError::RoleExpired)
+            }
         }
     }
 }
@@ -64,6 +76,9 @@ impl From<OwnableError> for TimelockControllerError {
         match ownable {
             OwnableError::CallerIsNotOwner => TimelockControllerError::Custom(String::from("O::CallerIsNotOwner")),
             OwnableError::NewOwnerIsZero => TimelockControllerError::Custom(String::from("O::NewOwnerIsZero")),
+            OwnableError::CallerIsNotPendingOwner => {
+                TimelockControllerError::Custom(String::from("O::CallerIsNotPendingOwner"))
+            }
         }
     }
 }
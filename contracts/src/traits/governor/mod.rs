@@ -0,0 +1,223 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::{
+    errors::GovernorError,
+    timelock_controller::Transaction,
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    BlockNumber,
+    Hash,
+    String,
+};
+
+pub type ProposalId = Hash;
+
+/// The state of the proposal during its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ProposalState {
+    /// The proposal was created, but the voting has not started yet.
+    Pending,
+    /// The voting is in progress.
+    Active,
+    /// The proposal was canceled by the proposer or by the governance.
+    Canceled,
+    /// The voting is over, and the proposal didn't reach the quorum or the majority.
+    Defeated,
+    /// The voting is over, and the proposal can be queued.
+    Succeeded,
+    /// The proposal is scheduled in the timelock.
+    Queued,
+    /// The transactions of the proposal were executed by the timelock.
+    Executed,
+}
+
+/// The type of the vote
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum VoteType {
+    Against,
+    For,
+    Abstain,
+}
+
+/// The votes casted for the proposal
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct ProposalVote {
+    pub against_votes: Balance,
+    pub for_votes: Balance,
+    pub abstain_votes: Balance,
+}
+
+#[openbrush::wrapper]
+pub type GovernorRef = dyn Governor;
+
+/// Contract module for on-chain governance. Holders of a [`PSP22Votes`](crate::traits::psp22::extensions::votes::PSP22Votes)
+/// token create proposals and vote for them with the voting power that they had at the start of the voting.
+///
+/// Succeeded proposals are scheduled in a `TimelockController` and executed by it
+/// after the delay, so the governor must have the proposer and the executor roles in the timelock.
+/// Usually the timelock is also the only one who can change the settings of the governor.
+#[openbrush::trait_definition]
+pub trait Governor {
+    /// Returns the id of the proposal built from its transactions and the hash of its description.
+    #[ink(message)]
+    fn hash_proposal(&self, transactions: Vec<Transaction>, description_hash: [u8; 32]) -> ProposalId;
+
+    /// Returns the current state of the proposal.
+    #[ink(message)]
+    fn state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError>;
+
+    /// Returns the block number used to retrieve the voting power of voters.
+    /// The voting starts after this block.
+    #[ink(message)]
+    fn proposal_snapshot(&self, proposal_id: ProposalId) -> Result<BlockNumber, GovernorError>;
+
+    /// Returns the last block number when voting is possible.
+    #[ink(message)]
+    fn proposal_deadline(&self, proposal_id: ProposalId) -> Result<BlockNumber, GovernorError>;
+
+    /// Returns the account that created the proposal.
+    #[ink(message)]
+    fn proposal_proposer(&self, proposal_id: ProposalId) -> Result<AccountId, GovernorError>;
+
+    /// Returns the votes casted for the proposal.
+    #[ink(message)]
+    fn proposal_votes(&self, proposal_id: ProposalId) -> ProposalVote;
+
+    /// Returns whether the `account` has casted a vote for the proposal.
+    #[ink(message)]
+    fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool;
+
+    /// Returns the address of the token that is used to calculate the voting power.
+    #[ink(message)]
+    fn token(&self) -> Option<AccountId>;
+
+    /// Returns the address of the timelock that executes proposals.
+    #[ink(message)]
+    fn timelock(&self) -> Option<AccountId>;
+
+    /// Returns the amount of blocks between the creation of the proposal and the start of the voting.
+    #[ink(message)]
+    fn voting_delay(&self) -> BlockNumber;
+
+    /// Returns the amount of blocks during which the voting is possible.
+    #[ink(message)]
+    fn voting_period(&self) -> BlockNumber;
+
+    /// Returns the amount of votes required to create a proposal.
+    #[ink(message)]
+    fn proposal_threshold(&self) -> Balance;
+
+    /// Returns the amount of votes required for a proposal to succeed at `block_number`.
+    #[ink(message)]
+    fn quorum(&self, block_number: BlockNumber) -> Result<Balance, GovernorError>;
+
+    /// Returns the quorum numerator. The quorum is the part of the total supply
+    /// calculated as `numerator / denominator`.
+    #[ink(message)]
+    fn quorum_numerator(&self) -> u128;
+
+    /// Returns the quorum denominator.
+    #[ink(message)]
+    fn quorum_denominator(&self) -> u128;
+
+    /// Returns the voting power of the `account` at `block_number`.
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId, block_number: BlockNumber) -> Result<Balance, GovernorError>;
+
+    /// Creates a new proposal. The voting starts after `voting_delay` blocks
+    /// and lasts `voting_period` blocks.
+    ///
+    /// On success a `ProposalCreated` event is emitted.
+    ///
+    /// Note: The caller must have at least `proposal_threshold` votes at the previous block.
+    #[ink(message)]
+    fn propose(&mut self, transactions: Vec<Transaction>, description: String) -> Result<ProposalId, GovernorError>;
+
+    /// Casts the vote of the caller for the active proposal.
+    ///
+    /// On success a `VoteCast` event is emitted.
+    #[ink(message)]
+    fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType) -> Result<Balance, GovernorError>;
+
+    /// Schedules the succeeded proposal in the timelock.
+    ///
+    /// On success a `ProposalQueued` event is emitted.
+    #[ink(message)]
+    fn queue(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Executes the queued proposal through the timelock, once the timelock delay has passed.
+    /// The value transferred by the transactions is paid from the balance of the timelock.
+    ///
+    /// On success a `ProposalExecuted` event is emitted.
+    #[ink(message)]
+    fn execute(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Cancels the proposal before the voting has started.
+    ///
+    /// On success a `ProposalCanceled` event is emitted.
+    ///
+    /// Note: The caller must be the proposer of the proposal.
+    #[ink(message)]
+    fn cancel(
+        &mut self,
+        transactions: Vec<Transaction>,
+        description_hash: [u8; 32],
+    ) -> Result<ProposalId, GovernorError>;
+
+    /// Changes the voting delay.
+    ///
+    /// Note: The caller must be the timelock.
+    #[ink(message)]
+    fn set_voting_delay(&mut self, new_voting_delay: BlockNumber) -> Result<(), GovernorError>;
+
+    /// Changes the voting period.
+    ///
+    /// Note: The caller must be the timelock.
+    #[ink(message)]
+    fn set_voting_period(&mut self, new_voting_period: BlockNumber) -> Result<(), GovernorError>;
+
+    /// Changes the proposal threshold.
+    ///
+    /// Note: The caller must be the timelock.
+    #[ink(message)]
+    fn set_proposal_threshold(&mut self, new_proposal_threshold: Balance) -> Result<(), GovernorError>;
+
+    /// Changes the quorum numerator.
+    ///
+    /// Note: The caller must be the timelock.
+    #[ink(message)]
+    fn update_quorum_numerator(&mut self, new_quorum_numerator: u128) -> Result<(), GovernorError>;
+}
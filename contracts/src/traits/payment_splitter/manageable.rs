@@ -0,0 +1,120 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+pub use crate::traits::errors::PaymentSplitterError;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+#[openbrush::wrapper]
+pub type PaymentSplitterManageableRef = dyn PaymentSplitterManageable;
+
+/// Extension of `PaymentSplitter` that allows the owner to change the payees and their shares after the deployment.
+///
+/// Before any change, the pending payments of every payee are credited to them, so nobody loses the payments received
+/// under the previous shares. The credited amounts are transferred by `release` and `release_token`, also to the removed
+/// payees. The credited amounts are counted as released, the part of them not transferred yet is returned by `owed`
+/// and `owed_token`. After the change the `released` amounts are rebased to the new shares.
+///
+/// Only the PSP22 tokens added with `add_token` are settled. A token should be added before the contract receives it,
+/// otherwise the payments received before the addition are split by the shares at the time of the addition.
+#[openbrush::trait_definition]
+pub trait PaymentSplitterManageable {
+    /// Adds a new `payee` with the amount of `shares`.
+    ///
+    /// On success a `PayeeAdded` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not owner.
+    ///
+    /// Returns with `SharesAreZero` error if `shares` is zero.
+    ///
+    /// Returns with `AlreadyHasShares` error if `payee` already has shares.
+    ///
+    /// Returns with `TooManyPayees` error if the number of payees reached the limit.
+    ///
+    /// Returns with `TokenBalanceFailed` error if the balance of a tracked token can't be queried.
+    #[ink(message)]
+    fn add_payee(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError>;
+
+    /// Removes the `payee` and its shares.
+    ///
+    /// On success a `PayeeRemoved` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not owner.
+    ///
+    /// Returns with `AccountHasNoShares` error if `payee` has no shares.
+    ///
+    /// Returns with `NoPayees` error if `payee` is the last payee.
+    ///
+    /// Returns with `TokenBalanceFailed` error if the balance of a tracked token can't be queried.
+    #[ink(message)]
+    fn remove_payee(&mut self, payee: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Sets the amount of `shares` held by the `payee`.
+    ///
+    /// On success a `SharesUpdated` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not owner.
+    ///
+    /// Returns with `SharesAreZero` error if `shares` is zero.
+    ///
+    /// Returns with `AccountHasNoShares` error if `payee` has no shares.
+    ///
+    /// Returns with `TokenBalanceFailed` error if the balance of a tracked token can't be queried.
+    #[ink(message)]
+    fn update_shares(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError>;
+
+    /// Adds the PSP22 `token` to the tracked tokens, which can be released and are settled before the payees change.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not owner.
+    ///
+    /// Returns with `TooManyTokens` error if the number of tokens reached the limit.
+    #[ink(message)]
+    fn add_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Removes the PSP22 `token` from the tracked tokens, for example if its contract traps.
+    /// The amounts of the `token` already credited to the payees can still be released.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `CallerIsNotOwner` error if caller is not owner.
+    ///
+    /// Returns with `TokenIsNotTracked` error if `token` is not tracked.
+    #[ink(message)]
+    fn remove_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Getter for the amount of native token credited to `account` and not transferred yet.
+    #[ink(message)]
+    fn owed(&self, account: AccountId) -> Balance;
+
+    /// Getter for the amount of PSP22 `token` credited to `account` and not transferred yet.
+    #[ink(message)]
+    fn owed_token(&self, token: AccountId, account: AccountId) -> Balance;
+}
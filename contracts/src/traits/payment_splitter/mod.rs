@@ -20,11 +20,14 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 pub use crate::traits::errors::PaymentSplitterError;
+use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
     Balance,
 };
 
+pub mod manageable;
+
 #[openbrush::wrapper]
 pub type PaymentSplitterRef = dyn PaymentSplitter;
 
@@ -38,7 +41,10 @@ pub type PaymentSplitterRef = dyn PaymentSplitter;
 /// `PaymentSplitter` follows a pull payment model. This means that payments are not automatically forwarded to the
 /// accounts but kept in this contract, and the actual transfer is triggered as a separate step by calling the `release`
 /// function.
-// TODO: Support payments in PSP22
+///
+/// PSP22 tokens held by the contract are split in the same proportion. Each token is accounted separately, and the
+/// payments are released with the `release_token` function. Only the tokens added with `Internal::_add_token` can
+/// be released, so the contract doesn't track the tokens sent by anyone.
 #[openbrush::trait_definition]
 pub trait PaymentSplitter {
     /// Getter for the total shares held by payees.
@@ -46,6 +52,7 @@ pub trait PaymentSplitter {
     fn total_shares(&self) -> Balance;
 
     /// Getter for the total amount of native token already released.
+    /// It includes the amounts credited to the payees when the payees changed, see `PaymentSplitterManageable`.
     #[ink(message)]
     fn total_released(&self) -> Balance;
 
@@ -54,6 +61,7 @@ pub trait PaymentSplitter {
     fn shares(&self, account: AccountId) -> Balance;
 
     /// Getter for the amount of native token already released to a payee.
+    /// It includes the amount credited to the payee when the payees changed, see `PaymentSplitterManageable`.
     #[ink(message)]
     fn released(&self, account: AccountId) -> Balance;
 
@@ -61,6 +69,12 @@ pub trait PaymentSplitter {
     #[ink(message)]
     fn payee(&self, index: u32) -> Option<AccountId>;
 
+    /// Returns up to `limit` payees starting from the `start` index.
+    ///
+    /// The page size is bounded by the implementation, a shorter page doesn't mean the end of the list.
+    #[ink(message)]
+    fn payees(&self, start: u128, limit: u128) -> Vec<AccountId>;
+
     /// The native token received will be logged with `PaymentReceived` events.
     /// Note that these events are not fully reliable: a contract can receive a native token
     /// without triggering this function. This only affects the reliability of the events
@@ -76,4 +90,25 @@ pub trait PaymentSplitter {
     /// On success a `PaymentReleased` event is emitted.
     #[ink(message)]
     fn release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError>;
+
+    /// Getter for the total amount of `token` already released.
+    /// It includes the amounts credited to the payees when the payees changed, see `PaymentSplitterManageable`.
+    #[ink(message)]
+    fn total_released_token(&self, token: AccountId) -> Balance;
+
+    /// Getter for the amount of `token` already released to a payee.
+    /// It includes the amount credited to the payee when the payees changed, see `PaymentSplitterManageable`.
+    #[ink(message)]
+    fn released_token(&self, token: AccountId, account: AccountId) -> Balance;
+
+    /// Triggers a transfer to `account` of the amount of PSP22 `token` they are owed, according to their percentage
+    /// of the total shares and their previous withdrawals of that token.
+    ///
+    /// On success a `PSP22PaymentReleased` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns with `TokenIsNotTracked` error if `token` is not tracked and `account` is not owed any of it.
+    #[ink(message)]
+    fn release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError>;
 }
@@ -0,0 +1,80 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that allows approvals to be made via signatures,
+/// so the owner doesn't need to send a transaction to approve the spender.
+/// The implementation is enabled by the `psp22_permit` feature
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+/// Signature of the permit, the kind of the signature defines how the signer is matched with `owner`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum PermitSignature {
+    /// ECDSA signature in the `r || s || v` format, `owner` is the hash of the compressed public key
+    Ecdsa([u8; 65]),
+    /// Sr25519 signature made with the `substrate` signing context, `owner` is the public key
+    Sr25519([u8; 64]),
+}
+
+#[openbrush::wrapper]
+pub type PSP22PermitRef = dyn PSP22Permit;
+
+#[openbrush::trait_definition]
+pub trait PSP22Permit {
+    /// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+    /// given `owner`'s signed approval.
+    ///
+    /// The signed message is the hash of `domain_separator`, `owner`, `spender`, `value`,
+    /// the current nonce of `owner` and `deadline`. The `owner` is the account
+    /// of the ECDSA or sr25519 public key that signed the message, see [`PermitSignature`].
+    ///
+    /// On success a `Approval` event is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PermitExpired` error if `deadline` is in the past.
+    ///
+    /// Returns `PermitInvalidSignature` error if the signature is not made by `owner`.
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+        signature: PermitSignature,
+    ) -> Result<(), PSP22Error>;
+
+    /// Returns the current nonce of `owner`. Every successful `permit` increases it,
+    /// so the same signature can't be used twice.
+    #[ink(message)]
+    fn nonces(&self, owner: AccountId) -> u64;
+
+    /// Returns the domain separator used in the signed message of the permit.
+    /// It is bound to the account of the contract.
+    #[ink(message)]
+    fn domain_separator(&self) -> [u8; 32];
+}
@@ -0,0 +1,48 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that records balances and the total supply at the moments
+/// of snapshots, so they can be queried later
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+pub type SnapshotId = u32;
+
+#[openbrush::wrapper]
+pub type PSP22SnapshotRef = dyn PSP22Snapshot;
+
+#[openbrush::trait_definition]
+pub trait PSP22Snapshot {
+    /// Returns the balance of `account` at the moment when the snapshot `snapshot_id` was taken.
+    ///
+    /// On error returns `PSP22Error::Custom` if the snapshot doesn't exist.
+    #[ink(message)]
+    fn balance_of_at(&self, account: AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply at the moment when the snapshot `snapshot_id` was taken.
+    ///
+    /// On error returns `PSP22Error::Custom` if the snapshot doesn't exist.
+    #[ink(message)]
+    fn total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error>;
+}
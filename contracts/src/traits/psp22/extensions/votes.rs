@@ -0,0 +1,61 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Extension of [`PSP22`] that supports vote delegation and keeps a history of voting power
+pub use crate::traits::errors::PSP22Error;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    BlockNumber,
+};
+
+#[openbrush::wrapper]
+pub type PSP22VotesRef = dyn PSP22Votes;
+
+#[openbrush::trait_definition]
+pub trait PSP22Votes {
+    /// Returns the account to which `account` has delegated its votes, if any.
+    #[ink(message)]
+    fn delegates(&self, account: AccountId) -> Option<AccountId>;
+
+    /// Returns the current amount of votes that `account` has.
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> Balance;
+
+    /// Returns the amount of votes that `account` had at the end of the block `block_number`.
+    ///
+    /// On error returns `PSP22Error::Custom` if `block_number` is not yet mined.
+    #[ink(message)]
+    fn get_past_votes(&self, account: AccountId, block_number: BlockNumber) -> Result<Balance, PSP22Error>;
+
+    /// Returns the total supply of votes available at the end of the block `block_number`.
+    /// The value is the sum of all balances, not only the delegated ones.
+    ///
+    /// On error returns `PSP22Error::Custom` if `block_number` is not yet mined.
+    #[ink(message)]
+    fn get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, PSP22Error>;
+
+    /// Delegates votes of the caller to `delegatee`.
+    ///
+    /// On success a `DelegateChanged` event is emitted.
+    #[ink(message)]
+    fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error>;
+}
@@ -29,6 +29,9 @@ pub mod extensions {
     pub mod capped;
     pub mod metadata;
     pub mod mintable;
+    pub mod permit;
+    pub mod snapshot;
+    pub mod votes;
     pub mod wrapper;
 }
 
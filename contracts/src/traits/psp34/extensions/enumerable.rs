@@ -22,6 +22,7 @@
 /// Extension of [`PSP34`] that adds enumerability of all the token ids in the contract as well
 /// as all token ids owned by each account.
 pub use crate::traits::psp34::*;
+use ink::prelude::vec::Vec;
 use openbrush::traits::AccountId;
 
 #[openbrush::wrapper]
@@ -42,4 +43,18 @@ pub trait PSP34Enumerable: PSP34 {
     /// The start index is zero.
     #[ink(message)]
     fn token_by_index(&self, index: u128) -> Result<Id, PSP34Error>;
+
+    /// Returns up to `limit` token ids owned by `owner` starting from the `start` index of its token list.
+    ///
+    /// The page size is bounded by the implementation, a shorter page doesn't mean the end of the list.
+    /// Use along with `balance_of` to enumerate all of ``owner``'s tokens.
+    #[ink(message)]
+    fn tokens_of_owner(&self, owner: AccountId, start: u128, limit: u128) -> Vec<Id>;
+
+    /// Returns up to `limit` token ids starting from the `start` index of all the tokens stored by the contract.
+    ///
+    /// The page size is bounded by the implementation, a shorter page doesn't mean the end of the list.
+    /// Use along with `total_supply` to enumerate all tokens.
+    #[ink(message)]
+    fn tokens(&self, start: u128, limit: u128) -> Vec<Id>;
 }
@@ -34,4 +34,16 @@ pub trait PSP34Metadata {
     /// If `id` is a collection id of the token, it returns attributes for collection.
     #[ink(message)]
     fn get_attribute(&self, id: Id, key: String) -> Option<String>;
+
+    /// Returns the base URI of the collection, `None` if it is not set.
+    #[ink(message)]
+    fn base_uri(&self) -> Option<String>;
+
+    /// Returns the URI of the token `id`.
+    ///
+    /// If the token has its own URI, it is returned, otherwise the rendered `id` is appended
+    /// to the base URI. Number ids are rendered in decimal and `Id::Bytes` in lowercase hex.
+    /// Returns `None` if the token `id` doesn't exist or neither the token URI nor the base URI is set.
+    #[ink(message)]
+    fn token_uri(&self, id: Id) -> Option<String>;
 }
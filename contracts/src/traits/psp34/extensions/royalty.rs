@@ -0,0 +1,52 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+/// Royalty info for PSP34 (PSP-2981 style)
+pub use crate::traits::errors::PSP34Error;
+pub use crate::traits::psp34::Id;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// Royalty receiver and its fraction of the sale price.
+///
+/// `royalty_fraction` is expressed in units of the fee denominator (basis points by default).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct RoyaltyInfo {
+    pub receiver: AccountId,
+    pub royalty_fraction: u128,
+}
+
+#[openbrush::wrapper]
+pub type PSP34RoyaltyRef = dyn PSP34Royalty;
+
+#[openbrush::trait_definition]
+pub trait PSP34Royalty {
+    /// Returns the receiver of the royalty for token `id` and the royalty amount
+    /// that should be paid to it for the `sale_price`.
+    ///
+    /// The royalty of the token is used if it is set, otherwise the default royalty.
+    /// If no royalty is set, returns the zero account and zero amount.
+    #[ink(message)]
+    fn royalty_info(&self, id: Id, sale_price: Balance) -> (AccountId, Balance);
+}
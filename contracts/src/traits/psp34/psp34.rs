@@ -90,3 +90,28 @@ pub trait PSP34 {
     #[ink(message)]
     fn total_supply(&self) -> Balance;
 }
+
+#[openbrush::wrapper]
+pub type PSP34ReceiverRef = dyn PSP34Receiver;
+
+/// PSP34Receiver is an interface for any contract that wants to support safe transfers from a PSP34 token smart contract.
+#[openbrush::trait_definition]
+pub trait PSP34Receiver {
+    /// Ensures that the smart contract allows reception of PSP34 token.
+    /// Returns `Ok(())` if the contract allows the reception of the token and Error `TransferRejected(String))` otherwise.
+    ///
+    /// This method will get called on every transfer to check whether the recipient in `transfer` is a contract,
+    /// and if it is, does it accept the token.
+    /// `from` is `None` when the token is minted.
+    /// This is done to prevent contracts from locking tokens forever.
+    ///
+    /// Returns `TransferRejected` if the contract does not accept the token.
+    #[ink(message)]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: Option<AccountId>,
+        id: Id,
+        data: Vec<u8>,
+    ) -> Result<(), PSP34ReceiverError>;
+}
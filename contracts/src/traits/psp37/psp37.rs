@@ -105,3 +105,28 @@ pub trait PSP37 {
         data: Vec<u8>,
     ) -> Result<(), PSP37Error>;
 }
+
+#[openbrush::wrapper]
+pub type PSP37ReceiverRef = dyn PSP37Receiver;
+
+/// PSP37Receiver is an interface for any contract that wants to support safe transfers from a PSP37 multi token smart contract.
+#[openbrush::trait_definition]
+pub trait PSP37Receiver {
+    /// Ensures that the smart contract allows reception of PSP37 tokens.
+    /// Returns `Ok(())` if the contract allows the reception of the tokens and Error `TransferRejected(String))` otherwise.
+    ///
+    /// This method will get called on every transfer to check whether the recipient in `transfer` or
+    /// `batch_transfer` is a contract, and if it is, does it accept the tokens.
+    /// `ids_amounts` contains all tokens of the transfer, `from` is `None` when the tokens are minted.
+    /// This is done to prevent contracts from locking tokens forever.
+    ///
+    /// Returns `TransferRejected` if the contract does not accept the tokens.
+    #[ink(message)]
+    fn before_received(
+        &mut self,
+        operator: AccountId,
+        from: Option<AccountId>,
+        ids_amounts: Vec<(Id, Balance)>,
+        data: Vec<u8>,
+    ) -> Result<(), PSP37ReceiverError>;
+}
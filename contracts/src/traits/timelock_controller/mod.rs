@@ -45,10 +45,40 @@ pub struct Transaction {
     pub input: Vec<u8>,
     /// The amount of chain balance that is transferred to the callee.
     pub transferred_value: Balance,
-    /// Gas limit for the execution of the call.
+    /// Gas limit for the execution of the call. Zero means that all remaining gas is forwarded.
     pub gas_limit: u64,
 }
 
+/// The operation that is scheduled in the timelock
+#[derive(Debug, Default, Clone, PartialEq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+pub struct Operation {
+    /// The transactions that are executed by the operation.
+    pub transactions: Vec<Transaction>,
+    /// The operation that must be done before the execution of this operation.
+    pub predecessor: Option<OperationId>,
+    /// The salt that is used to distinguish operations with the same transactions.
+    pub salt: [u8; 32],
+}
+
+/// The state of the operation during its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Encode, scale::Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum OperationState {
+    /// The operation was never scheduled.
+    Unset,
+    /// The operation is scheduled, but the delay has not passed yet.
+    Waiting,
+    /// The operation can be executed.
+    Ready,
+    /// The operation was executed.
+    Done,
+    /// The operation was cancelled before the execution.
+    Cancelled,
+    /// The operation was not executed in time and can't be executed anymore.
+    Expired,
+}
+
 /// TimelockController is AccessControl itself, so creating wrapper for both traits
 #[openbrush::wrapper]
 pub type TimelockControllerRef = dyn TimelockController + AccessControl;
@@ -83,11 +113,44 @@ pub trait TimelockController: AccessControl {
     #[ink(message)]
     fn is_operation_done(&self, id: OperationId) -> bool;
 
+    /// Returns whether an operation is expired or not.
+    /// The operation is expired if it was not executed within the grace period after becoming ready.
+    #[ink(message)]
+    fn is_operation_expired(&self, id: OperationId) -> bool;
+
     /// Returns the timestamp at with an operation becomes ready (0 for
     /// unset operations, 1 for done operations).
     #[ink(message)]
     fn get_timestamp(&self, id: OperationId) -> Timestamp;
 
+    /// Returns the period after the operation becomes ready, during which it can be executed.
+    /// Zero means that operations never expire.
+    ///
+    /// This value can be changed by executing an operation that calls `update_grace_period`.
+    #[ink(message)]
+    fn get_grace_period(&self) -> Timestamp;
+
+    /// Returns the state of an operation.
+    #[ink(message)]
+    fn get_operation_state(&self, id: OperationId) -> OperationState;
+
+    /// Returns the operation that was scheduled with `id`.
+    #[ink(message)]
+    fn get_operation(&self, id: OperationId) -> Option<Operation>;
+
+    /// Returns up to `limit` ids of pending operations starting from the `start` index of the pending list.
+    /// Pending operations were scheduled, but neither executed nor cancelled yet.
+    ///
+    /// The page size is bounded by the implementation, a shorter page doesn't mean the end of the list.
+    /// Use along with `pending_operations_count` to enumerate all pending operations.
+    /// Operations are not sorted in any particular way, and their ordering may change at any point.
+    #[ink(message)]
+    fn pending_operations(&self, start: u128, limit: u128) -> Vec<OperationId>;
+
+    /// Returns the number of operations that were scheduled, but neither executed nor cancelled yet.
+    #[ink(message)]
+    fn pending_operations_count(&self) -> u128;
+
     /// Returns the minimum delay for an operation to become valid.
     ///
     /// This value can be changed by executing an operation that calls `update_delay`.
@@ -146,6 +209,8 @@ pub trait TimelockController: AccessControl {
     fn cancel(&mut self, id: OperationId) -> Result<(), TimelockControllerError>;
 
     /// Execute an (ready) operation containing a single transaction.
+    /// The `transferred_value` of the transaction must be covered by the balance of the timelock.
+    /// The part of the value sent by the caller that is not transferred to the callee is refunded.
     ///
     /// Emits a `CallExecuted` event.
     ///
@@ -159,6 +224,8 @@ pub trait TimelockController: AccessControl {
     ) -> Result<(), TimelockControllerError>;
 
     /// Execute an (ready) operation containing a batch of transactions.
+    /// The sum of `transferred_value` of transactions must be covered by the balance of the timelock.
+    /// The part of the value sent by the caller that is not transferred to callees is refunded.
     ///
     /// Emits one `CallExecuted` event per transaction in the batch.
     ///
@@ -181,4 +248,16 @@ pub trait TimelockController: AccessControl {
     /// ABI-encoded call to this function.
     #[ink(message)]
     fn update_delay(&mut self, new_delay: Timestamp) -> Result<(), TimelockControllerError>;
+
+    /// Changes the grace period for the execution of ready operations.
+    /// It also affects the operations that are already scheduled.
+    ///
+    /// Emits a `GracePeriodChange` event.
+    ///
+    /// Note: The caller must be the timelock itself.
+    /// This can only be achieved by scheduling and later executing
+    /// an operation where the timelock is the target and the data is the
+    /// ABI-encoded call to this function.
+    #[ink(message)]
+    fn update_grace_period(&mut self, new_grace_period: Timestamp) -> Result<(), TimelockControllerError>;
 }
@@ -5,6 +5,11 @@ use quote::{
     quote_spanned,
 };
 use syn::{
+    parse::{
+        Parse,
+        ParseStream,
+    },
+    punctuated::Punctuated,
     spanned::Spanned,
     Data,
     DataStruct,
@@ -12,8 +17,104 @@ use syn::{
     Fields,
 };
 
+/// Arguments of `#[openbrush::accessors(Trait: Bounds, modifiers(...), error = Error)]`
+struct AccessorsArgs {
+    trait_ident: syn::Ident,
+    supertraits: Punctuated<syn::TypeParamBound, syn::Token![+]>,
+    setter: SetterArgs,
+}
+
+impl Parse for AccessorsArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let trait_ident = input.parse()?;
+        let mut supertraits = Punctuated::new();
+
+        if input.peek(syn::Token![:]) {
+            let _: syn::Token![:] = input.parse()?;
+            supertraits = Punctuated::parse_separated_nonempty(input)?;
+        }
+
+        let setter = if input.is_empty() {
+            SetterArgs::default()
+        } else {
+            let _: syn::Token![,] = input.parse()?;
+            input.parse()?
+        };
+
+        Ok(Self {
+            trait_ident,
+            supertraits,
+            setter,
+        })
+    }
+}
+
+/// Arguments of setters: `modifiers(...)` to wrap the setter and `error = Error` to return `Result<(), Error>`
+#[derive(Default, Clone)]
+struct SetterArgs {
+    modifiers: Option<TokenStream>,
+    error: Option<syn::Type>,
+}
+
+impl Parse for SetterArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut args = SetterArgs::default();
+
+        while !input.is_empty() {
+            let ident: syn::Ident = input.parse()?;
+
+            if ident == "modifiers" {
+                let content;
+                syn::parenthesized!(content in input);
+                args.modifiers = Some(content.parse()?);
+            } else if ident == "error" {
+                let _: syn::Token![=] = input.parse()?;
+                args.error = Some(input.parse()?);
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expected `modifiers(...)` or `error = Type`",
+                ))
+            }
+
+            if !input.is_empty() {
+                let _: syn::Token![,] = input.parse()?;
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+impl SetterArgs {
+    fn of_field(field: &Field, default: &SetterArgs) -> syn::Result<SetterArgs> {
+        let attr = field
+            .attrs
+            .iter()
+            .find(|a| a.path.is_ident("set"))
+            .expect("Setter must have `set` attribute");
+        let args = if attr.tokens.is_empty() {
+            SetterArgs::default()
+        } else {
+            attr.parse_args()?
+        };
+
+        Ok(SetterArgs {
+            modifiers: args.modifiers.or_else(|| default.modifiers.clone()),
+            error: args.error.or_else(|| default.error.clone()),
+        })
+    }
+}
+
 pub fn accessors(attrs: TokenStream, s: synstructure::Structure) -> TokenStream {
-    let trait_ident = attrs;
+    let AccessorsArgs {
+        trait_ident,
+        supertraits,
+        setter: default_setter,
+    } = match syn::parse2::<AccessorsArgs>(attrs) {
+        Ok(args) => args,
+        Err(err) => return err.to_compile_error(),
+    };
 
     let struct_ident = s.ast().ident.clone();
 
@@ -30,10 +131,30 @@ pub fn accessors(attrs: TokenStream, s: synstructure::Structure) -> TokenStream
         let field_type = field.ty.clone();
         let span = field.span();
 
-        quote_spanned! {span =>
-            #[ink(message)]
-            fn #method_ident(&self) -> #field_type {
-                self.data().#field_ident
+        match field_kind(field) {
+            FieldKind::Mapping(key_type, value_type) => {
+                quote_spanned! {span =>
+                    #[ink(message)]
+                    fn #method_ident(&self, key: #key_type) -> Option<#value_type> {
+                        self.data::<#struct_ident>().#field_ident.get(&key)
+                    }
+                }
+            }
+            FieldKind::Lazy => {
+                quote_spanned! {span =>
+                    #[ink(message)]
+                    fn #method_ident(&self) -> #field_type {
+                        self.data::<#struct_ident>().#field_ident.get_or_default()
+                    }
+                }
+            }
+            FieldKind::Plain => {
+                quote_spanned! {span =>
+                    #[ink(message)]
+                    fn #method_ident(&self) -> #field_type {
+                        self.data::<#struct_ident>().#field_ident
+                    }
+                }
             }
         }
     });
@@ -46,19 +167,77 @@ pub fn accessors(attrs: TokenStream, s: synstructure::Structure) -> TokenStream
         let field_type = field.ty.clone();
         let span = field.span();
 
-        quote_spanned! {span =>
-            #[ink(message)]
-            fn #method_ident(&mut self, value: #field_type) {
-                self.data().#field_ident = value;
+        let setter = match SetterArgs::of_field(field, &default_setter) {
+            Ok(setter) => setter,
+            Err(err) => return err.to_compile_error(),
+        };
+
+        let (inputs, store) = match field_kind(field) {
+            FieldKind::Mapping(key_type, value_type) => {
+                (
+                    quote! { key: #key_type, value: #value_type },
+                    quote! { self.data::<#struct_ident>().#field_ident.insert(&key, &value); },
+                )
+            }
+            FieldKind::Lazy => {
+                (
+                    quote! { value: #field_type },
+                    quote! { self.data::<#struct_ident>().#field_ident.set(&value); },
+                )
+            }
+            FieldKind::Plain => {
+                (
+                    quote! { value: #field_type },
+                    quote! { self.data::<#struct_ident>().#field_ident = value; },
+                )
+            }
+        };
+
+        match setter {
+            SetterArgs {
+                modifiers: Some(_),
+                error: None,
+            } => {
+                quote_spanned! {span =>
+                    compile_error!("Setter with modifiers must return `Result`, specify the error type with `error = Type`");
+                }
+            }
+            SetterArgs {
+                modifiers,
+                error: Some(error),
+            } => {
+                let modifiers = modifiers.map(|modifiers| quote! { #[::openbrush::modifiers(#modifiers)] });
+
+                quote_spanned! {span =>
+                    #[ink(message)]
+                    #modifiers
+                    fn #method_ident(&mut self, #inputs) -> Result<(), #error> {
+                        #store
+                        Ok(())
+                    }
+                }
+            }
+            SetterArgs {
+                modifiers: None,
+                error: None,
+            } => {
+                quote_spanned! {span =>
+                    #[ink(message)]
+                    fn #method_ident(&mut self, #inputs) {
+                        #store
+                    }
+                }
             }
         }
     });
 
+    let supertraits = supertraits.iter();
+
     let result = quote! {
         #item
 
         #[openbrush::trait_definition]
-        pub trait #trait_ident : Storage<#struct_ident>{
+        pub trait #trait_ident : Storage<#struct_ident> #(+ #supertraits)* {
             #(#get_impls)*
             #(#set_impls)*
         }
@@ -67,6 +246,41 @@ pub fn accessors(attrs: TokenStream, s: synstructure::Structure) -> TokenStream
     result
 }
 
+enum FieldKind {
+    /// `Mapping<K, V>` field, accessors take the key of the mapping
+    Mapping(Box<syn::Type>, Box<syn::Type>),
+    /// `#[lazy]` field, accessors load and store the value
+    Lazy,
+    Plain,
+}
+
+fn field_kind(field: &Field) -> FieldKind {
+    if field.attrs.iter().any(|a| a.path.is_ident("lazy")) {
+        return FieldKind::Lazy
+    }
+
+    if let syn::Type::Path(path) = &field.ty {
+        if let Some(segment) = path.path.segments.last() {
+            if segment.ident == "Mapping" {
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    let mut types = args.args.iter().filter_map(|arg| {
+                        match arg {
+                            syn::GenericArgument::Type(ty) => Some(Box::new(ty.clone())),
+                            _ => None,
+                        }
+                    });
+
+                    if let (Some(key_type), Some(value_type)) = (types.next(), types.next()) {
+                        return FieldKind::Mapping(key_type, value_type)
+                    }
+                }
+            }
+        }
+    }
+
+    FieldKind::Plain
+}
+
 fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> TokenStream {
     let struct_ident = s.ast().ident.clone();
     let vis = s.ast().vis.clone();
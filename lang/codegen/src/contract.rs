@@ -53,7 +53,8 @@ pub fn generate(_attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     // After, we can consume all other stuff.
     items = consume_traits(items);
 
-    let generated_items = generate_impls(items);
+    let mut generated_items = generate_impls(items);
+    generated_items.extend(storage_keys_assertion(&generated_items));
 
     module.content = Some((braces, generated_items));
 
@@ -114,3 +115,62 @@ fn generate_impls(mut items: Vec<syn::Item>) -> Vec<syn::Item> {
 
     generated_items
 }
+
+// Fails the compilation if several fields of the contract storage use the same storage key.
+// It checks the storage keys of the storage items (including the nested ones)
+// and the storage keys of the fields with an explicit `ManualKey`.
+fn storage_keys_assertion(items: &[syn::Item]) -> Option<syn::Item> {
+    let storage_struct = items.iter().find_map(|item| {
+        if let Item::Struct(item_struct) = item {
+            if item_struct.attrs.iter().any(|attr| {
+                attr.path.is_ident("ink")
+                    && matches!(attr.parse_args::<syn::Path>(), Ok(path) if path.is_ident("storage"))
+            }) {
+                return Some(item_struct)
+            }
+        }
+        None
+    })?;
+
+    let (field_names, field_keys): (Vec<_>, Vec<_>) = storage_struct
+        .fields
+        .iter()
+        .filter_map(|field| {
+            let field_name = field.ident.as_ref()?.to_string();
+            let ty = &field.ty;
+            let has_manual_key = ty
+                .to_token_stream()
+                .into_iter()
+                .any(|token| matches!(token, proc_macro2::TokenTree::Ident(ident) if ident == "ManualKey"));
+
+            let keys = if has_manual_key {
+                quote! {
+                    &[::openbrush::storage::StorageKeyEntry::Key(
+                        #field_name,
+                        <#ty as ::ink::storage::traits::StorageKey>::KEY,
+                    )]
+                }
+            } else {
+                quote! { ::openbrush::storage::StorageKeysProbe::<#ty>::STORAGE_KEYS }
+            };
+
+            Some((field_name, keys))
+        })
+        .unzip();
+
+    if field_keys.is_empty() {
+        return None
+    }
+
+    let assertion = quote! {
+        const _: () = {
+            use ::openbrush::storage::NoStorageKeys as _;
+
+            ::openbrush::storage::assert_unique_storage_keys(&[
+                #((#field_names, #field_keys)),*
+            ]);
+        };
+    };
+
+    Some(syn::parse2::<syn::Item>(assertion).expect("Can't parse storage keys assertion"))
+}
@@ -42,7 +42,7 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
     let input: TokenStream = ink_module;
 
     // map attribute args to default contract names
-    let args = syn::parse2::<AttributeArgs>(attrs)
+    let mut args = syn::parse2::<AttributeArgs>(attrs)
         .expect("No default contracts to implement provided")
         .iter()
         .map(|arg| {
@@ -53,6 +53,11 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
         })
         .collect::<Vec<String>>();
 
+    // `Ownable2Step` overrides the messages of `Ownable`, so it is implemented on top of it
+    if args.iter().any(|arg| arg == "Ownable2Step") && !args.iter().any(|arg| arg == "Ownable") {
+        args.push(String::from("Ownable"));
+    }
+
     let mut module = syn::parse2::<syn::ItemMod>(input).expect("Can't parse contract module");
     let (braces, items) = match module.clone().content {
         Some((brace, items)) => (brace, items),
@@ -85,6 +90,9 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "PSP22Capped" => impl_psp22_capped(&mut impl_args),
             "PSP22Wrapper" => impl_psp22_wrapper(&mut impl_args),
             "Flashmint" => impl_flashmint(&mut impl_args),
+            "PSP22Votes" => impl_psp22_votes(&mut impl_args),
+            "PSP22Permit" => impl_psp22_permit(&mut impl_args),
+            "PSP22Snapshot" => impl_psp22_snapshot(&mut impl_args),
             "PSP22TokenTimelock" => impl_token_timelock(&mut impl_args),
             "PSP22Pallet" => impl_psp22_pallet(&mut impl_args),
             "PSP22PalletBurnable" => impl_psp22_pallet_burnable(&mut impl_args),
@@ -95,6 +103,7 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "PSP34Mintable" => impl_psp34_mintable(&mut impl_args),
             "PSP34Metadata" => impl_psp34_metadata(&mut impl_args),
             "PSP34Enumerable" => impl_psp34_enumerable(&mut impl_args),
+            "PSP34Royalty" => impl_psp34_royalty(&mut impl_args),
             "PSP37" => impl_psp37(&mut impl_args),
             "PSP37Batch" => impl_psp37_batch(&mut impl_args),
             "PSP37Burnable" => impl_psp37_burnable(&mut impl_args),
@@ -102,11 +111,15 @@ pub fn generate(attrs: TokenStream, ink_module: TokenStream) -> TokenStream {
             "PSP37Mintable" => impl_psp37_mintable(&mut impl_args),
             "PSP37Enumerable" => impl_psp37_enumerable(&mut impl_args),
             "Ownable" => impl_ownable(&mut impl_args),
+            "Ownable2Step" => impl_ownable_2_step(&mut impl_args),
             "PaymentSplitter" => impl_payment_splitter(&mut impl_args),
+            "PaymentSplitterManageable" => impl_payment_splitter_manageable(&mut impl_args),
             "AccessControl" => impl_access_control(&mut impl_args),
             "AccessControlEnumerable" => impl_access_control_enumerable(&mut impl_args),
+            "AccessControlDefaultAdminRules" => impl_access_control_default_admin_rules(&mut impl_args),
             "Pausable" => impl_pausable(&mut impl_args),
             "TimelockController" => impl_timelock_controller(&mut impl_args),
+            "Governor" => impl_governor(&mut impl_args),
             "Proxy" => impl_proxy(&mut impl_args),
             "Diamond" => impl_diamond(&mut impl_args),
             "DiamondLoupe" => impl_diamond_loupe(&mut impl_args),
@@ -143,13 +156,22 @@ fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
         "PSP22Metadata",
         "PSP22Wrapper",
         "Flashmint",
+        "PSP22Votes",
+        "PSP22Permit",
+        "PSP22Snapshot",
     ];
     check_and_remove_import("PSP22", psp22_impls, imports);
 
     let psp22_pallet_impls = vec!["PSP22PalletMintable", "PSP22PalletBurnable", "PSP22PalletMetadata"];
     check_and_remove_import("PSP22Pallet", psp22_pallet_impls, imports);
 
-    let psp34_impls = vec!["PSP34Mintable", "PSP34Burnable", "PSP34Metadata", "PSP34Enumerable"];
+    let psp34_impls = vec![
+        "PSP34Mintable",
+        "PSP34Burnable",
+        "PSP34Metadata",
+        "PSP34Enumerable",
+        "PSP34Royalty",
+    ];
     check_and_remove_import("PSP34", psp34_impls, imports);
 
     let psp37_impls = vec![
@@ -161,9 +183,17 @@ fn cleanup_imports(imports: &mut HashMap<&str, syn::ItemUse>) {
     ];
     check_and_remove_import("PSP37", psp37_impls, imports);
 
-    let access_impls = vec!["AccessControlEnumerable", "TimelockController"];
+    let access_impls = vec![
+        "AccessControlEnumerable",
+        "AccessControlDefaultAdminRules",
+        "TimelockController",
+    ];
     check_and_remove_import("AccessControl", access_impls, imports);
 
+    check_and_remove_import("Ownable", vec!["Ownable2Step"], imports);
+
+    check_and_remove_import("PaymentSplitter", vec!["PaymentSplitterManageable"], imports);
+
     check_and_remove_import("Diamond", vec!["DiamondLoupe"], imports);
 }
 
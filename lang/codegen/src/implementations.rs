@@ -1,3 +1,4 @@
+use proc_macro2::TokenStream;
 use quote::{
     format_ident,
     quote,
@@ -46,13 +47,9 @@ impl<'a> ImplArgs<'a> {
     }
 }
 
-pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
+// `psp22::Internal` is stored in `overriden_traits`, because extensions can hook into its methods
+fn psp22_internal(impl_args: &ImplArgs) -> syn::ItemImpl {
     let storage_struct_name = impl_args.contract_name();
-    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp22::InternalImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
-
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
         impl psp22::Internal for #storage_struct_name {
             fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, amount: Balance) {
@@ -119,10 +116,36 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
             ) -> Result<(), PSP22Error> {
                 psp22::InternalImpl::_after_token_transfer(self, from, to, amount)
             }
+
+            fn _is_safe_transfer_check_enabled(&self) -> bool {
+                psp22::InternalImpl::_is_safe_transfer_check_enabled(self)
+            }
+
+            fn _do_safe_transfer_check(
+                &mut self,
+                from: Option<&AccountId>,
+                to: &AccountId,
+                value: &Balance,
+                data: Vec<u8>,
+            ) -> Result<(), PSP22Error> {
+                psp22::InternalImpl::_do_safe_transfer_check(self, from, to, value, data)
+            }
         }
     ))
     .expect("Should parse");
 
+    override_functions("psp22::Internal", &mut internal, impl_args.map);
+
+    internal
+}
+
+pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp22::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
     let psp22_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl PSP22Impl for #storage_struct_name {}
     ))
@@ -186,11 +209,16 @@ pub(crate) fn impl_psp22(impl_args: &mut ImplArgs) {
     impl_args.imports.insert("PSP22", import);
     impl_args.vec_import();
 
-    override_functions("psp22::Internal", &mut internal, impl_args.map);
+    let internal = psp22_internal(impl_args);
     override_functions("PSP22", &mut psp22, impl_args.map);
 
+    // only insert this if it is not present
+    impl_args
+        .overriden_traits
+        .entry("psp22::Internal")
+        .or_insert(syn::Item::Impl(internal));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(psp22_impl));
     impl_args.items.push(syn::Item::Impl(psp22));
 }
@@ -496,6 +524,307 @@ pub(crate) fn impl_flashmint(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(flashlender));
 }
 
+pub(crate) fn impl_psp22_votes(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl votes::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl votes::Internal for #storage_struct_name {
+            fn _emit_delegate_changed_event(
+                &self,
+                delegator: AccountId,
+                from_delegate: Option<AccountId>,
+                to_delegate: Option<AccountId>,
+            ) {
+                votes::InternalImpl::_emit_delegate_changed_event(self, delegator, from_delegate, to_delegate)
+            }
+
+            fn _emit_delegate_votes_changed_event(&self, delegate: AccountId, previous_votes: Balance, new_votes: Balance) {
+                votes::InternalImpl::_emit_delegate_votes_changed_event(self, delegate, previous_votes, new_votes)
+            }
+
+            fn _delegates(&self, account: &AccountId) -> Option<AccountId> {
+                votes::InternalImpl::_delegates(self, account)
+            }
+
+            fn _get_votes(&self, account: &AccountId) -> Balance {
+                votes::InternalImpl::_get_votes(self, account)
+            }
+
+            fn _get_past_votes(&self, account: &AccountId, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+                votes::InternalImpl::_get_past_votes(self, account, block_number)
+            }
+
+            fn _get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+                votes::InternalImpl::_get_past_total_supply(self, block_number)
+            }
+
+            fn _delegate(&mut self, delegator: &AccountId, delegatee: Option<AccountId>) -> Result<(), PSP22Error> {
+                votes::InternalImpl::_delegate(self, delegator, delegatee)
+            }
+
+            fn _transfer_voting_units(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                votes::InternalImpl::_transfer_voting_units(self, from, to, amount)
+            }
+
+            fn _move_delegate_votes(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                votes::InternalImpl::_move_delegate_votes(self, from, to, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let votes_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22VotesImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut votes = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Votes for #storage_struct_name {
+            #[ink(message)]
+            fn delegates(&self, account: AccountId) -> Option<AccountId> {
+                PSP22VotesImpl::delegates(self, account)
+            }
+
+            #[ink(message)]
+            fn get_votes(&self, account: AccountId) -> Balance {
+                PSP22VotesImpl::get_votes(self, account)
+            }
+
+            #[ink(message)]
+            fn get_past_votes(&self, account: AccountId, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+                PSP22VotesImpl::get_past_votes(self, account, block_number)
+            }
+
+            #[ink(message)]
+            fn get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, PSP22Error> {
+                PSP22VotesImpl::get_past_total_supply(self, block_number)
+            }
+
+            #[ink(message)]
+            fn delegate(&mut self, delegatee: AccountId) -> Result<(), PSP22Error> {
+                PSP22VotesImpl::delegate(self, delegatee)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::votes::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Votes", import);
+    impl_args.vec_import();
+
+    override_functions("votes::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Votes", &mut votes, impl_args.map);
+
+    // votes are moved after each mint, burn and transfer of tokens
+    let psp22_internal = psp22_internal(impl_args);
+    if let syn::Item::Impl(psp22_internal) = impl_args
+        .overriden_traits
+        .entry("psp22::Internal")
+        .or_insert(syn::Item::Impl(psp22_internal))
+    {
+        call_before(
+            psp22_internal,
+            "_after_token_transfer",
+            quote!(votes::Internal::_transfer_voting_units),
+        );
+    }
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(votes_impl));
+    impl_args.items.push(syn::Item::Impl(votes));
+}
+
+pub(crate) fn impl_psp22_permit(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl permit::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl permit::Internal for #storage_struct_name {
+            fn _domain_separator(&self) -> [u8; 32] {
+                permit::InternalImpl::_domain_separator(self)
+            }
+
+            fn _permit_message_hash(
+                &self,
+                owner: &AccountId,
+                spender: &AccountId,
+                value: &Balance,
+                nonce: u64,
+                deadline: &Timestamp,
+            ) -> [u8; 32] {
+                permit::InternalImpl::_permit_message_hash(self, owner, spender, value, nonce, deadline)
+            }
+
+            fn _verify_signature(
+                &self,
+                owner: &AccountId,
+                message_hash: &[u8; 32],
+                signature: &PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                permit::InternalImpl::_verify_signature(self, owner, message_hash, signature)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let permit_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22PermitImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut permit = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Permit for #storage_struct_name {
+            #[ink(message)]
+            fn permit(
+                &mut self,
+                owner: AccountId,
+                spender: AccountId,
+                value: Balance,
+                deadline: Timestamp,
+                signature: PermitSignature,
+            ) -> Result<(), PSP22Error> {
+                PSP22PermitImpl::permit(self, owner, spender, value, deadline, signature)
+            }
+
+            #[ink(message)]
+            fn nonces(&self, owner: AccountId) -> u64 {
+                PSP22PermitImpl::nonces(self, owner)
+            }
+
+            #[ink(message)]
+            fn domain_separator(&self) -> [u8; 32] {
+                PSP22PermitImpl::domain_separator(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::permit::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Permit", import);
+
+    override_functions("permit::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Permit", &mut permit, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(permit_impl));
+    impl_args.items.push(syn::Item::Impl(permit));
+}
+
+pub(crate) fn impl_psp22_snapshot(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl snapshot::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl snapshot::Internal for #storage_struct_name {
+            fn _emit_snapshot_event(&self, id: SnapshotId) {
+                snapshot::InternalImpl::_emit_snapshot_event(self, id)
+            }
+
+            fn _snapshot(&mut self) -> SnapshotId {
+                snapshot::InternalImpl::_snapshot(self)
+            }
+
+            fn _current_snapshot_id(&self) -> SnapshotId {
+                snapshot::InternalImpl::_current_snapshot_id(self)
+            }
+
+            fn _balance_of_at(&self, account: &AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                snapshot::InternalImpl::_balance_of_at(self, account, snapshot_id)
+            }
+
+            fn _total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                snapshot::InternalImpl::_total_supply_at(self, snapshot_id)
+            }
+
+            fn _update_snapshots(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                amount: &Balance,
+            ) -> Result<(), PSP22Error> {
+                snapshot::InternalImpl::_update_snapshots(self, from, to, amount)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let snapshot_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22SnapshotImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut snapshot = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP22Snapshot for #storage_struct_name {
+            #[ink(message)]
+            fn balance_of_at(&self, account: AccountId, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                PSP22SnapshotImpl::balance_of_at(self, account, snapshot_id)
+            }
+
+            #[ink(message)]
+            fn total_supply_at(&self, snapshot_id: SnapshotId) -> Result<Balance, PSP22Error> {
+                PSP22SnapshotImpl::total_supply_at(self, snapshot_id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp22::extensions::snapshot::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP22Snapshot", import);
+
+    override_functions("snapshot::Internal", &mut internal, impl_args.map);
+    override_functions("PSP22Snapshot", &mut snapshot, impl_args.map);
+
+    // balances are recorded before each mint, burn and transfer of tokens
+    let psp22_internal = psp22_internal(impl_args);
+    if let syn::Item::Impl(psp22_internal) = impl_args
+        .overriden_traits
+        .entry("psp22::Internal")
+        .or_insert(syn::Item::Impl(psp22_internal))
+    {
+        call_before(
+            psp22_internal,
+            "_before_token_transfer",
+            quote!(snapshot::Internal::_update_snapshots),
+        );
+    }
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(snapshot_impl));
+    impl_args.items.push(syn::Item::Impl(snapshot));
+}
+
 pub(crate) fn impl_token_timelock(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -791,13 +1120,9 @@ pub(crate) fn impl_psp22_pallet_mintable(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(mintable));
 }
 
-pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
+// `psp34::Internal` is stored in `overriden_traits`, because extensions can hook into its methods
+fn psp34_internal(impl_args: &ImplArgs) -> syn::ItemImpl {
     let storage_struct_name = impl_args.contract_name();
-    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl psp34::InternalImpl for #storage_struct_name {}
-    ))
-    .expect("Should parse");
-
     let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
         impl psp34::Internal for #storage_struct_name {
             fn _emit_transfer_event(&self, from: Option<AccountId>, to: Option<AccountId>, id: Id) {
@@ -853,10 +1178,32 @@ pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
             ) -> Result<(), PSP34Error> {
                 psp34::InternalImpl::_after_token_transfer(self, from, to, id)
             }
+
+            fn _do_safe_transfer_check(
+                &mut self,
+                from: Option<&AccountId>,
+                to: &AccountId,
+                id: &Id,
+                data: Vec<u8>,
+            ) -> Result<(), PSP34Error> {
+                psp34::InternalImpl::_do_safe_transfer_check(self, from, to, id, data)
+            }
         }
     ))
     .expect("Should parse");
 
+    override_functions("psp34::Internal", &mut internal, impl_args.map);
+
+    internal
+}
+
+pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl psp34::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
     let psp34_impl = syn::parse2::<syn::ItemImpl>(quote!(
         impl PSP34Impl for #storage_struct_name {}
     ))
@@ -959,8 +1306,8 @@ pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
     impl_args.imports.insert("PSP34", import);
     impl_args.vec_import();
 
+    let internal = psp34_internal(impl_args);
     override_functions("psp34::BalancesManager", &mut psp34_balances, impl_args.map);
-    override_functions("psp34::Internal", &mut internal, impl_args.map);
     override_functions("PSP34", &mut psp34, impl_args.map);
 
     // only insert this if it is not present
@@ -974,8 +1321,12 @@ pub(crate) fn impl_psp34(impl_args: &mut ImplArgs) {
         .entry("psp34::BalancesManagerImpl")
         .or_insert(syn::Item::Impl(psp34_balances_impl));
 
+    impl_args
+        .overriden_traits
+        .entry("psp34::Internal")
+        .or_insert(syn::Item::Impl(internal));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(psp34_impl));
     impl_args.items.push(syn::Item::Impl(psp34));
 }
@@ -1056,6 +1407,18 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
             fn _set_attribute(&mut self, id: Id, key: String, value: String) {
                 metadata::InternalImpl::_set_attribute(self, id, key, value)
             }
+
+            fn _set_base_uri(&mut self, uri: String) {
+                metadata::InternalImpl::_set_base_uri(self, uri)
+            }
+
+            fn _set_token_uri(&mut self, id: Id, uri: String) {
+                metadata::InternalImpl::_set_token_uri(self, id, uri)
+            }
+
+            fn _remove_token_uri(&mut self, id: Id) {
+                metadata::InternalImpl::_remove_token_uri(self, id)
+            }
         }
     ))
     .expect("Should parse");
@@ -1071,6 +1434,16 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
             fn get_attribute(&self, id: Id, key: String) -> Option<String> {
                 PSP34MetadataImpl::get_attribute(self, id, key)
             }
+
+            #[ink(message)]
+            fn base_uri(&self) -> Option<String> {
+                PSP34MetadataImpl::base_uri(self)
+            }
+
+            #[ink(message)]
+            fn token_uri(&self, id: Id) -> Option<String> {
+                PSP34MetadataImpl::token_uri(self, id)
+            }
         }
     ))
     .expect("Should parse");
@@ -1083,7 +1456,7 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
     impl_args.vec_import();
 
     override_functions("metadata::Internal", &mut internal, impl_args.map);
-    override_functions("PSP34Mintable", &mut metadata, impl_args.map);
+    override_functions("PSP34Metadata", &mut metadata, impl_args.map);
 
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
@@ -1091,24 +1464,129 @@ pub(crate) fn impl_psp34_metadata(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(metadata));
 }
 
-pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_psp34_royalty(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34EnumerableImpl for #storage_struct_name {}
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl royalty::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut psp34_enumerable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl PSP34Enumerable for #storage_struct_name {
-            #[ink(message)]
-            fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Result<Id, PSP34Error> {
-                PSP34EnumerableImpl::owners_token_by_index(self, owner, index)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl royalty::Internal for #storage_struct_name {
+            fn _fee_denominator(&self) -> u128 {
+                royalty::InternalImpl::_fee_denominator(self)
+            }
+
+            fn _royalty_info(&self, id: &Id, sale_price: Balance) -> (AccountId, Balance) {
+                royalty::InternalImpl::_royalty_info(self, id, sale_price)
+            }
+
+            fn _set_default_royalty(&mut self, receiver: AccountId, fee_numerator: u128) -> Result<(), PSP34Error> {
+                royalty::InternalImpl::_set_default_royalty(self, receiver, fee_numerator)
+            }
+
+            fn _delete_default_royalty(&mut self) {
+                royalty::InternalImpl::_delete_default_royalty(self)
+            }
+
+            fn _set_token_royalty(
+                &mut self,
+                id: &Id,
+                receiver: AccountId,
+                fee_numerator: u128,
+            ) -> Result<(), PSP34Error> {
+                royalty::InternalImpl::_set_token_royalty(self, id, receiver, fee_numerator)
+            }
+
+            fn _reset_token_royalty(&mut self, id: &Id) {
+                royalty::InternalImpl::_reset_token_royalty(self, id)
+            }
+
+            fn _clear_burned_token_royalty(
+                &mut self,
+                from: Option<&AccountId>,
+                to: Option<&AccountId>,
+                id: &Id,
+            ) -> Result<(), PSP34Error> {
+                royalty::InternalImpl::_clear_burned_token_royalty(self, from, to, id)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let royalty_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34RoyaltyImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut royalty = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Royalty for #storage_struct_name {
+            #[ink(message)]
+            fn royalty_info(&self, id: Id, sale_price: Balance) -> (AccountId, Balance) {
+                PSP34RoyaltyImpl::royalty_info(self, id, sale_price)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::psp34::extensions::royalty::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PSP34Royalty", import);
+    impl_args.vec_import();
+
+    override_functions("royalty::Internal", &mut internal, impl_args.map);
+    override_functions("PSP34Royalty", &mut royalty, impl_args.map);
+
+    // royalty of the token is cleared when it is burned
+    let psp34_internal = psp34_internal(impl_args);
+    if let syn::Item::Impl(psp34_internal) = impl_args
+        .overriden_traits
+        .entry("psp34::Internal")
+        .or_insert(syn::Item::Impl(psp34_internal))
+    {
+        call_before(
+            psp34_internal,
+            "_before_token_transfer",
+            quote!(royalty::Internal::_clear_burned_token_royalty),
+        );
+    }
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(royalty_impl));
+    impl_args.items.push(syn::Item::Impl(royalty));
+}
+
+pub(crate) fn impl_psp34_enumerable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34EnumerableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut psp34_enumerable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PSP34Enumerable for #storage_struct_name {
+            #[ink(message)]
+            fn owners_token_by_index(&self, owner: AccountId, index: u128) -> Result<Id, PSP34Error> {
+                PSP34EnumerableImpl::owners_token_by_index(self, owner, index)
             }
 
             #[ink(message)]
             fn token_by_index(&self, index: u128) -> Result<Id, PSP34Error> {
                 PSP34EnumerableImpl::token_by_index(self, index)
             }
+
+            #[ink(message)]
+            fn tokens_of_owner(&self, owner: AccountId, start: u128, limit: u128) -> Vec<Id> {
+                PSP34EnumerableImpl::tokens_of_owner(self, owner, start, limit)
+            }
+
+            #[ink(message)]
+            fn tokens(&self, start: u128, limit: u128) -> Vec<Id> {
+                PSP34EnumerableImpl::tokens(self, start, limit)
+            }
         }
 
     ))
@@ -1276,6 +1754,16 @@ pub(crate) fn impl_psp37(impl_args: &mut ImplArgs) {
             ) -> Result<(), PSP37Error> {
                 psp37::InternalImpl::_after_token_transfer(self, from, to, ids)
             }
+
+            fn _do_safe_transfer_check(
+                &mut self,
+                from: Option<&AccountId>,
+                to: &AccountId,
+                ids_amounts: &[(Id, Balance)],
+                data: Vec<u8>,
+            ) -> Result<(), PSP37Error> {
+                psp37::InternalImpl::_do_safe_transfer_check(self, from, to, ids_amounts, data)
+            }
         }
 
     ))
@@ -1748,10 +2236,89 @@ pub(crate) fn impl_ownable(impl_args: &mut ImplArgs) {
     override_functions("ownable::Internal", &mut internal, impl_args.map);
     override_functions("Ownable", &mut ownable, impl_args.map);
 
+    // `Ownable` is stored in `overriden_traits`, because `Ownable2Step` replaces the transfer of the ownership
+    impl_args
+        .overriden_traits
+        .entry("Ownable")
+        .or_insert(syn::Item::Impl(ownable));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
     impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(ownable_impl));
-    impl_args.items.push(syn::Item::Impl(ownable));
+}
+
+pub(crate) fn impl_ownable_2_step(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl ownable_2_step::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl ownable_2_step::Internal for #storage_struct_name {
+            fn _emit_ownership_transfer_started_event(&self, previous: Option<AccountId>, new: AccountId) {
+                ownable_2_step::InternalImpl::_emit_ownership_transfer_started_event(self, previous, new)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let ownable_2_step_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Ownable2StepImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut ownable_2_step = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Ownable2Step for #storage_struct_name {
+            #[ink(message)]
+            fn pending_owner(&self) -> Option<AccountId> {
+                Ownable2StepImpl::pending_owner(self)
+            }
+
+            #[ink(message)]
+            fn accept_ownership(&mut self) -> Result<(), OwnableError> {
+                Ownable2StepImpl::accept_ownership(self)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mut ownable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Ownable for #storage_struct_name {
+            #[ink(message)]
+            fn owner(&self) -> Option<AccountId> {
+                OwnableImpl::owner(self)
+            }
+
+            #[ink(message)]
+            fn renounce_ownership(&mut self) -> Result<(), OwnableError> {
+                Ownable2StepImpl::renounce_ownership(self)
+            }
+
+            #[ink(message)]
+            fn transfer_ownership(&mut self, new_owner: AccountId) -> Result<(), OwnableError> {
+                Ownable2StepImpl::transfer_ownership(self, new_owner)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::ownable::extensions::ownable_2_step::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Ownable2Step", import);
+
+    override_functions("ownable_2_step::Internal", &mut internal, impl_args.map);
+    override_functions("Ownable2Step", &mut ownable_2_step, impl_args.map);
+    override_functions("Ownable", &mut ownable, impl_args.map);
+
+    impl_args.overriden_traits.insert("Ownable", syn::Item::Impl(ownable));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(ownable_2_step_impl));
+    impl_args.items.push(syn::Item::Impl(ownable_2_step));
 }
 
 pub(crate) fn impl_payment_splitter(impl_args: &mut ImplArgs) {
@@ -1775,6 +2342,18 @@ pub(crate) fn impl_payment_splitter(impl_args: &mut ImplArgs) {
                 payment_splitter::InternalImpl::_emit_payment_released_event(self, to, amount)
             }
 
+            fn _emit_psp22_payment_released_event(&self, token: AccountId, to: AccountId, amount: Balance) {
+                payment_splitter::InternalImpl::_emit_psp22_payment_released_event(self, token, to, amount)
+            }
+
+            fn _emit_payee_removed_event(&self, account: AccountId) {
+                payment_splitter::InternalImpl::_emit_payee_removed_event(self, account)
+            }
+
+            fn _emit_shares_updated_event(&self, account: AccountId, shares: Balance) {
+                payment_splitter::InternalImpl::_emit_shares_updated_event(self, account, shares)
+            }
+
             fn _init(&mut self, payees_and_shares: Vec<(AccountId, Balance)>) -> Result<(), PaymentSplitterError> {
                 payment_splitter::InternalImpl::_init(self, payees_and_shares)
             }
@@ -1783,13 +2362,61 @@ pub(crate) fn impl_payment_splitter(impl_args: &mut ImplArgs) {
                 payment_splitter::InternalImpl::_add_payee(self, payee, share)
             }
 
+            fn _remove_payee(&mut self, payee: AccountId) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_remove_payee(self, payee)
+            }
+
+            fn _update_shares(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_update_shares(self, payee, shares)
+            }
+
             fn _release_all(&mut self) -> Result<(), PaymentSplitterError> {
                 payment_splitter::InternalImpl::_release_all(self)
             }
 
+            fn _migrate_payees(&mut self) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_migrate_payees(self)
+            }
+
+            fn _add_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_add_token(self, token)
+            }
+
+            fn _remove_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_remove_token(self, token)
+            }
+
+            fn _settle(&mut self, account: AccountId, current_balance: Balance) -> Balance {
+                payment_splitter::InternalImpl::_settle(self, account, current_balance)
+            }
+
+            fn _settle_token(&mut self, token: AccountId, account: AccountId, current_balance: Balance) -> Balance {
+                payment_splitter::InternalImpl::_settle_token(self, token, account, current_balance)
+            }
+
+            fn _settle_all(&mut self) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_settle_all(self)
+            }
+
+            fn _token_balance(&self, token: AccountId) -> Result<Balance, PaymentSplitterError> {
+                payment_splitter::InternalImpl::_token_balance(self, token)
+            }
+
+            fn _transfer_token(&mut self, token: AccountId, to: AccountId, amount: Balance) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_transfer_token(self, token, to, amount)
+            }
+
+            fn _rebase_released(&mut self) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_rebase_released(self)
+            }
+
             fn _release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError> {
                 payment_splitter::InternalImpl::_release(self, account)
             }
+
+            fn _release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError> {
+                payment_splitter::InternalImpl::_release_token(self, token, account)
+            }
         }
     ))
     .expect("Should parse");
@@ -1826,6 +2453,11 @@ pub(crate) fn impl_payment_splitter(impl_args: &mut ImplArgs) {
                 PaymentSplitterImpl::payee(self, index)
             }
 
+            #[ink(message)]
+            fn payees(&self, start: u128, limit: u128) -> Vec<AccountId> {
+                PaymentSplitterImpl::payees(self, start, limit)
+            }
+
             #[ink(message)]
             fn receive(&mut self) {
                 PaymentSplitterImpl::receive(self)
@@ -1835,6 +2467,21 @@ pub(crate) fn impl_payment_splitter(impl_args: &mut ImplArgs) {
             fn release(&mut self, account: AccountId) -> Result<(), PaymentSplitterError> {
                 PaymentSplitterImpl::release(self, account)
             }
+
+            #[ink(message)]
+            fn total_released_token(&self, token: AccountId) -> Balance {
+                PaymentSplitterImpl::total_released_token(self, token)
+            }
+
+            #[ink(message)]
+            fn released_token(&self, token: AccountId, account: AccountId) -> Balance {
+                PaymentSplitterImpl::released_token(self, token, account)
+            }
+
+            #[ink(message)]
+            fn release_token(&mut self, token: AccountId, account: AccountId) -> Result<(), PaymentSplitterError> {
+                PaymentSplitterImpl::release_token(self, token, account)
+            }
         }
     ))
     .expect("Should parse");
@@ -1855,6 +2502,69 @@ pub(crate) fn impl_payment_splitter(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(payment_splitter));
 }
 
+pub(crate) fn impl_payment_splitter_manageable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let payment_splitter_manageable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PaymentSplitterManageableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut payment_splitter_manageable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl PaymentSplitterManageable for #storage_struct_name {
+            #[ink(message)]
+            fn add_payee(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError> {
+                PaymentSplitterManageableImpl::add_payee(self, payee, shares)
+            }
+
+            #[ink(message)]
+            fn remove_payee(&mut self, payee: AccountId) -> Result<(), PaymentSplitterError> {
+                PaymentSplitterManageableImpl::remove_payee(self, payee)
+            }
+
+            #[ink(message)]
+            fn update_shares(&mut self, payee: AccountId, shares: Balance) -> Result<(), PaymentSplitterError> {
+                PaymentSplitterManageableImpl::update_shares(self, payee, shares)
+            }
+
+            #[ink(message)]
+            fn add_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+                PaymentSplitterManageableImpl::add_token(self, token)
+            }
+
+            #[ink(message)]
+            fn remove_token(&mut self, token: AccountId) -> Result<(), PaymentSplitterError> {
+                PaymentSplitterManageableImpl::remove_token(self, token)
+            }
+
+            #[ink(message)]
+            fn owed(&self, account: AccountId) -> Balance {
+                PaymentSplitterManageableImpl::owed(self, account)
+            }
+
+            #[ink(message)]
+            fn owed_token(&self, token: AccountId, account: AccountId) -> Balance {
+                PaymentSplitterManageableImpl::owed_token(self, token, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::payment_splitter::extensions::manageable::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("PaymentSplitterManageable", import);
+
+    override_functions(
+        "PaymentSplitterManageable",
+        &mut payment_splitter_manageable,
+        impl_args.map,
+    );
+
+    impl_args.items.push(syn::Item::Impl(payment_splitter_manageable_impl));
+    impl_args.items.push(syn::Item::Impl(payment_splitter_manageable));
+}
+
 pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -1933,6 +2643,21 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
                 AccessControlImpl::grant_role(self, role, account)
             }
 
+            #[ink(message)]
+            fn grant_role_with_expiry(
+                &mut self,
+                role: RoleType,
+                account: Option<AccountId>,
+                expiry: Timestamp,
+            ) -> Result<(), AccessControlError> {
+                AccessControlImpl::grant_role_with_expiry(self, role, account, expiry)
+            }
+
+            #[ink(message)]
+            fn role_expiry(&self, role: RoleType, account: Option<AccountId>) -> Option<Timestamp> {
+                AccessControlImpl::role_expiry(self, role, account)
+            }
+
             #[ink(message)]
             fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
                 AccessControlImpl::revoke_role(self, role, account)
@@ -1972,6 +2697,14 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
             fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
                 access_control::MembersManagerImpl::_set_role_admin(self, role, new_admin)
             }
+
+            fn _get_role_expiry(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+                access_control::MembersManagerImpl::_get_role_expiry(self, role, member)
+            }
+
+            fn _set_role_expiry(&mut self, role: RoleType, member: &Option<AccountId>, expiry: Option<Timestamp>) {
+                access_control::MembersManagerImpl::_set_role_expiry(self, role, member, expiry)
+            }
         }
     ))
     .expect("Should parse");
@@ -1997,50 +2730,251 @@ pub(crate) fn impl_access_control(impl_args: &mut ImplArgs) {
         .entry("access_control::MembersManager")
         .or_insert(syn::Item::Impl(members));
 
+    // `access_control::Internal` and `AccessControl` are stored in `overriden_traits`,
+    // because `AccessControlDefaultAdminRules` hooks into the management of the default admin role
+    impl_args
+        .overriden_traits
+        .entry("access_control::Internal")
+        .or_insert(syn::Item::Impl(internal));
+
+    impl_args
+        .overriden_traits
+        .entry("AccessControl")
+        .or_insert(syn::Item::Impl(access_control));
+
     impl_args.items.push(syn::Item::Impl(internal_impl));
-    impl_args.items.push(syn::Item::Impl(internal));
     impl_args.items.push(syn::Item::Impl(access_control_impl));
-    impl_args.items.push(syn::Item::Impl(access_control));
 }
 
-pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
+pub(crate) fn impl_access_control_default_admin_rules(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
-    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl AccessControlEnumerableImpl for #storage_struct_name {}
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl default_admin_rules::InternalImpl for #storage_struct_name {}
     ))
     .expect("Should parse");
 
-    let mut enumerable = syn::parse2::<syn::ItemImpl>(quote!(
-        impl AccessControlEnumerable for #storage_struct_name {
-            #[ink(message)]
-            fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId> {
-                AccessControlEnumerableImpl::get_role_member(self, role, index)
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl default_admin_rules::Internal for #storage_struct_name {
+            fn _emit_default_admin_transfer_scheduled(&mut self, new_admin: Option<AccountId>, accept_schedule: Timestamp) {
+                default_admin_rules::InternalImpl::_emit_default_admin_transfer_scheduled(self, new_admin, accept_schedule)
             }
 
-            #[ink(message)]
-            fn get_role_member_count(&self, role: RoleType) -> u32 {
-                AccessControlEnumerableImpl::get_role_member_count(self, role)
+            fn _emit_default_admin_transfer_canceled(&mut self) {
+                default_admin_rules::InternalImpl::_emit_default_admin_transfer_canceled(self)
             }
-        }
-    ))
-    .expect("Should parse");
 
-    let members_impl = syn::parse2::<syn::ItemImpl>(quote!(
-        impl enumerable::MembersManagerImpl for #storage_struct_name {}
+            fn _emit_default_admin_delay_changed(&mut self, old_delay: Timestamp, new_delay: Timestamp) {
+                default_admin_rules::InternalImpl::_emit_default_admin_delay_changed(self, old_delay, new_delay)
+            }
+
+            fn _init_with_default_admin(&mut self, admin: Option<AccountId>, delay: Timestamp) {
+                default_admin_rules::InternalImpl::_init_with_default_admin(self, admin, delay)
+            }
+        }
     ))
     .expect("Should parse");
 
-    let mut members = syn::parse2::<syn::ItemImpl>(quote!(
-        impl access_control::MembersManager for #storage_struct_name {
-            fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
-                enumerable::MembersManagerImpl::_has_role(self, role, address)
+    let mut access_control_internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl access_control::Internal for #storage_struct_name {
+            fn _emit_role_admin_changed(&mut self, role: RoleType, previous: RoleType, new: RoleType) {
+                access_control::InternalImpl::_emit_role_admin_changed(self, role, previous, new);
             }
 
-            fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
-                enumerable::MembersManagerImpl::_add(self, role, member)
+            fn _emit_role_granted(&mut self, role: RoleType, grantee: Option<AccountId>, grantor: Option<AccountId>) {
+                access_control::InternalImpl::_emit_role_granted(self, role, grantee, grantor);
             }
 
-            fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
+            fn _emit_role_revoked(&mut self, role: RoleType, account: Option<AccountId>, sender: AccountId) {
+                access_control::InternalImpl::_emit_role_revoked(self, role, account, sender);
+            }
+
+            fn _default_admin() -> RoleType {
+                <Self as access_control::InternalImpl>::_default_admin()
+            }
+
+            fn _init_with_caller(&mut self) {
+                access_control::InternalImpl::_init_with_caller(self);
+            }
+
+            fn _init_with_admin(&mut self, admin: Option<AccountId>) {
+                access_control::InternalImpl::_init_with_admin(self, admin);
+            }
+
+            fn _setup_role(&mut self, role: RoleType, member: Option<AccountId>) {
+                default_admin_rules::InternalImpl::_setup_role(self, role, member);
+            }
+
+            fn _do_revoke_role(&mut self, role: RoleType, account: Option<AccountId>) {
+                default_admin_rules::InternalImpl::_do_revoke_role(self, role, account);
+            }
+
+            fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
+                access_control::InternalImpl::_set_role_admin(self, role, new_admin);
+            }
+
+            fn _check_role(&self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                access_control::InternalImpl::_check_role(self, role, account)
+            }
+
+            fn _get_role_admin(&self, role: RoleType) -> RoleType {
+                access_control::InternalImpl::_get_role_admin(self, role)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let rules_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlDefaultAdminRulesImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut rules = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlDefaultAdminRules for #storage_struct_name {
+            #[ink(message)]
+            fn default_admin(&self) -> Option<AccountId> {
+                AccessControlDefaultAdminRulesImpl::default_admin(self)
+            }
+
+            #[ink(message)]
+            fn pending_default_admin(&self) -> (Option<AccountId>, Timestamp) {
+                AccessControlDefaultAdminRulesImpl::pending_default_admin(self)
+            }
+
+            #[ink(message)]
+            fn default_admin_delay(&self) -> Timestamp {
+                AccessControlDefaultAdminRulesImpl::default_admin_delay(self)
+            }
+
+            #[ink(message)]
+            fn begin_default_admin_transfer(&mut self, new_admin: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::begin_default_admin_transfer(self, new_admin)
+            }
+
+            #[ink(message)]
+            fn cancel_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::cancel_default_admin_transfer(self)
+            }
+
+            #[ink(message)]
+            fn accept_default_admin_transfer(&mut self) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::accept_default_admin_transfer(self)
+            }
+
+            #[ink(message)]
+            fn change_default_admin_delay(&mut self, new_delay: Timestamp) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::change_default_admin_delay(self, new_delay)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let mut access_control = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControl for #storage_struct_name {
+            #[ink(message)]
+            fn has_role(&self, role: RoleType, address: Option<AccountId>) -> bool {
+                AccessControlImpl::has_role(self, role, address)
+            }
+
+            #[ink(message)]
+            fn get_role_admin(&self, role: RoleType) -> RoleType {
+                AccessControlImpl::get_role_admin(self, role)
+            }
+
+            #[ink(message)]
+            fn grant_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::grant_role(self, role, account)
+            }
+
+            #[ink(message)]
+            fn grant_role_with_expiry(
+                &mut self,
+                role: RoleType,
+                account: Option<AccountId>,
+                expiry: Timestamp,
+            ) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::grant_role_with_expiry(self, role, account, expiry)
+            }
+
+            #[ink(message)]
+            fn role_expiry(&self, role: RoleType, account: Option<AccountId>) -> Option<Timestamp> {
+                AccessControlImpl::role_expiry(self, role, account)
+            }
+
+            #[ink(message)]
+            fn revoke_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::revoke_role(self, role, account)
+            }
+
+            #[ink(message)]
+            fn renounce_role(&mut self, role: RoleType, account: Option<AccountId>) -> Result<(), AccessControlError> {
+                AccessControlDefaultAdminRulesImpl::renounce_role(self, role, account)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::access_control::extensions::default_admin_rules::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("AccessControlDefaultAdminRules", import);
+
+    override_functions("default_admin_rules::Internal", &mut internal, impl_args.map);
+    override_functions("access_control::Internal", &mut access_control_internal, impl_args.map);
+    override_functions("AccessControlDefaultAdminRules", &mut rules, impl_args.map);
+    override_functions("AccessControl", &mut access_control, impl_args.map);
+
+    impl_args
+        .overriden_traits
+        .insert("access_control::Internal", syn::Item::Impl(access_control_internal));
+    impl_args
+        .overriden_traits
+        .insert("AccessControl", syn::Item::Impl(access_control));
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(rules_impl));
+    impl_args.items.push(syn::Item::Impl(rules));
+}
+
+pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let enumerable_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlEnumerableImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut enumerable = syn::parse2::<syn::ItemImpl>(quote!(
+        impl AccessControlEnumerable for #storage_struct_name {
+            #[ink(message)]
+            fn get_role_member(&self, role: RoleType, index: u32) -> Option<AccountId> {
+                AccessControlEnumerableImpl::get_role_member(self, role, index)
+            }
+
+            #[ink(message)]
+            fn get_role_member_count(&self, role: RoleType) -> u32 {
+                AccessControlEnumerableImpl::get_role_member_count(self, role)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let members_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl enumerable::MembersManagerImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut members = syn::parse2::<syn::ItemImpl>(quote!(
+        impl access_control::MembersManager for #storage_struct_name {
+            fn _has_role(&self, role: RoleType, address: &Option<AccountId>) -> bool {
+                enumerable::MembersManagerImpl::_has_role(self, role, address)
+            }
+
+            fn _add(&mut self, role: RoleType, member: &Option<AccountId>) {
+                enumerable::MembersManagerImpl::_add(self, role, member)
+            }
+
+            fn _remove(&mut self, role: RoleType, member: &Option<AccountId>) {
                 enumerable::MembersManagerImpl::_remove(self, role, member)
             }
 
@@ -2051,6 +2985,14 @@ pub(crate) fn impl_access_control_enumerable(impl_args: &mut ImplArgs) {
             fn _set_role_admin(&mut self, role: RoleType, new_admin: RoleType) {
                 enumerable::MembersManagerImpl::_set_role_admin(self, role, new_admin)
             }
+
+            fn _get_role_expiry(&self, role: RoleType, member: &Option<AccountId>) -> Option<Timestamp> {
+                enumerable::MembersManagerImpl::_get_role_expiry(self, role, member)
+            }
+
+            fn _set_role_expiry(&mut self, role: RoleType, member: &Option<AccountId>, expiry: Option<Timestamp>) {
+                enumerable::MembersManagerImpl::_set_role_expiry(self, role, member, expiry)
+            }
         }
     ))
     .expect("Should parse");
@@ -2155,6 +3097,10 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
                 timelock_controller::InternalImpl::_emit_min_delay_change_event(self, old_delay, new_delay)
             }
     
+            fn _emit_grace_period_change_event(&self, old_grace_period: Timestamp, new_grace_period: Timestamp) {
+                timelock_controller::InternalImpl::_emit_grace_period_change_event(self, old_grace_period, new_grace_period)
+            }
+    
             fn _emit_call_scheduled_event(
                 &self,
                 id: OperationId,
@@ -2170,8 +3116,8 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
                 timelock_controller::InternalImpl::_emit_cancelled_event(self, id)
             }
     
-            fn _emit_call_executed_event(&self, id: OperationId, index: u8, transaction: Transaction) {
-                timelock_controller::InternalImpl::_emit_call_executed_event(self, id, index, transaction)
+            fn _emit_call_executed_event(&self, id: OperationId, index: u8, transaction: Transaction, output: Vec<u8>) {
+                timelock_controller::InternalImpl::_emit_call_executed_event(self, id, index, transaction, output)
             }
     
             fn _init_with_caller(&mut self, min_delay: Timestamp, proposers: Vec<AccountId>, executors: Vec<AccountId>) {
@@ -2210,6 +3156,10 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
                 timelock_controller::InternalImpl::_schedule(self, id, delay)
             }
     
+            fn _store_operation(&mut self, id: OperationId, operation: &Operation) {
+                timelock_controller::InternalImpl::_store_operation(self, id, operation)
+            }
+    
             fn _before_call(&self, predecessor: Option<OperationId>) -> Result<(), TimelockControllerError> {
                 timelock_controller::InternalImpl::_before_call(self, predecessor)
             }
@@ -2222,6 +3172,14 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
                 timelock_controller::InternalImpl::_call(self, id, i, transaction)
             }
     
+            fn _check_transferred_value(&self, transactions: &[Transaction]) -> Result<Balance, TimelockControllerError> {
+                timelock_controller::InternalImpl::_check_transferred_value(self, transactions)
+            }
+    
+            fn _refund_unspent_value(&mut self, spent: Balance) -> Result<(), TimelockControllerError> {
+                timelock_controller::InternalImpl::_refund_unspent_value(self, spent)
+            }
+    
             fn _timelock_admin_role() -> RoleType {
                 <Self as timelock_controller::InternalImpl>::_timelock_admin_role()
             }
@@ -2250,9 +3208,17 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
                 timelock_controller::InternalImpl::_is_operation_done(self, id)
             }
     
+            fn _is_operation_expired(&self, id: OperationId) -> bool {
+                timelock_controller::InternalImpl::_is_operation_expired(self, id)
+            }
+    
             fn _get_timestamp(&self, id: OperationId) -> Timestamp {
                 timelock_controller::InternalImpl::_get_timestamp(self, id)
             }
+    
+            fn _get_operation_state(&self, id: OperationId) -> OperationState {
+                timelock_controller::InternalImpl::_get_operation_state(self, id)
+            }
         }
     ))
         .expect("Should parse");
@@ -2285,11 +3251,41 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
                 TimelockControllerImpl::is_operation_done(self, id)
             }
     
+            #[ink(message)]
+            fn is_operation_expired(&self, id: OperationId) -> bool {
+                TimelockControllerImpl::is_operation_expired(self, id)
+            }
+    
             #[ink(message)]
             fn get_timestamp(&self, id: OperationId) -> Timestamp {
                 TimelockControllerImpl::get_timestamp(self, id)
             }
     
+            #[ink(message)]
+            fn get_grace_period(&self) -> Timestamp {
+                TimelockControllerImpl::get_grace_period(self)
+            }
+    
+            #[ink(message)]
+            fn get_operation_state(&self, id: OperationId) -> OperationState {
+                TimelockControllerImpl::get_operation_state(self, id)
+            }
+    
+            #[ink(message)]
+            fn get_operation(&self, id: OperationId) -> Option<Operation> {
+                TimelockControllerImpl::get_operation(self, id)
+            }
+    
+            #[ink(message)]
+            fn pending_operations(&self, start: u128, limit: u128) -> Vec<OperationId> {
+                TimelockControllerImpl::pending_operations(self, start, limit)
+            }
+    
+            #[ink(message)]
+            fn pending_operations_count(&self) -> u128 {
+                TimelockControllerImpl::pending_operations_count(self)
+            }
+    
             #[ink(message)]
             fn get_min_delay(&self) -> Timestamp {
                 TimelockControllerImpl::get_min_delay(self)
@@ -2361,6 +3357,11 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
             fn update_delay(&mut self, new_delay: Timestamp) -> Result<(), TimelockControllerError> {
                 TimelockControllerImpl::update_delay(self, new_delay)
             }
+    
+            #[ink(message)]
+            fn update_grace_period(&mut self, new_grace_period: Timestamp) -> Result<(), TimelockControllerError> {
+                TimelockControllerImpl::update_grace_period(self, new_grace_period)
+            }
         }
     ))
         .expect("Should parse");
@@ -2381,6 +3382,296 @@ pub(crate) fn impl_timelock_controller(impl_args: &mut ImplArgs) {
     impl_args.items.push(syn::Item::Impl(timelock_controller));
 }
 
+pub(crate) fn impl_governor(impl_args: &mut ImplArgs) {
+    let storage_struct_name = impl_args.contract_name();
+    let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl governor::InternalImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    #[rustfmt::skip]
+    let mut internal = syn::parse2::<syn::ItemImpl>(quote!(
+        impl governor::Internal for #storage_struct_name {
+            fn _emit_proposal_created_event(
+                &self,
+                proposal_id: ProposalId,
+                proposer: AccountId,
+                transactions: Vec<Transaction>,
+                vote_start: BlockNumber,
+                vote_end: BlockNumber,
+                description: String,
+            ) {
+                governor::InternalImpl::_emit_proposal_created_event(self, proposal_id, proposer, transactions, vote_start, vote_end, description)
+            }
+
+            fn _emit_vote_cast_event(&self, proposal_id: ProposalId, voter: AccountId, support: VoteType, weight: Balance) {
+                governor::InternalImpl::_emit_vote_cast_event(self, proposal_id, voter, support, weight)
+            }
+
+            fn _emit_proposal_queued_event(&self, proposal_id: ProposalId) {
+                governor::InternalImpl::_emit_proposal_queued_event(self, proposal_id)
+            }
+
+            fn _emit_proposal_executed_event(&self, proposal_id: ProposalId) {
+                governor::InternalImpl::_emit_proposal_executed_event(self, proposal_id)
+            }
+
+            fn _emit_proposal_canceled_event(&self, proposal_id: ProposalId) {
+                governor::InternalImpl::_emit_proposal_canceled_event(self, proposal_id)
+            }
+
+            fn _init(
+                &mut self,
+                token: AccountId,
+                timelock: AccountId,
+                voting_delay: BlockNumber,
+                voting_period: BlockNumber,
+                proposal_threshold: Balance,
+                quorum_numerator: u128,
+            ) -> Result<(), GovernorError> {
+                governor::InternalImpl::_init(self, token, timelock, voting_delay, voting_period, proposal_threshold, quorum_numerator)
+            }
+
+            fn _hash_proposal(&self, transactions: &[Transaction], description_hash: &[u8; 32]) -> ProposalId {
+                governor::InternalImpl::_hash_proposal(self, transactions, description_hash)
+            }
+
+            fn _hash_description(&self, description: &str) -> [u8; 32] {
+                governor::InternalImpl::_hash_description(self, description)
+            }
+
+            fn _proposal(&self, proposal_id: ProposalId) -> Result<ProposalCore, GovernorError> {
+                governor::InternalImpl::_proposal(self, proposal_id)
+            }
+
+            fn _state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError> {
+                governor::InternalImpl::_state(self, proposal_id)
+            }
+
+            fn _quorum(&self, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+                governor::InternalImpl::_quorum(self, block_number)
+            }
+
+            fn _quorum_denominator() -> u128 {
+                <Self as governor::InternalImpl>::_quorum_denominator()
+            }
+
+            fn _quorum_reached(&self, proposal_id: ProposalId) -> Result<bool, GovernorError> {
+                governor::InternalImpl::_quorum_reached(self, proposal_id)
+            }
+
+            fn _vote_succeeded(&self, proposal_id: ProposalId) -> bool {
+                governor::InternalImpl::_vote_succeeded(self, proposal_id)
+            }
+
+            fn _count_vote(
+                &mut self,
+                proposal_id: ProposalId,
+                account: &AccountId,
+                support: VoteType,
+                weight: Balance,
+            ) -> Result<(), GovernorError> {
+                governor::InternalImpl::_count_vote(self, proposal_id, account, support, weight)
+            }
+
+            fn _cancel(
+                &mut self,
+                transactions: Vec<Transaction>,
+                description_hash: [u8; 32],
+            ) -> Result<ProposalId, GovernorError> {
+                governor::InternalImpl::_cancel(self, transactions, description_hash)
+            }
+
+            fn _set_voting_delay(&mut self, new_voting_delay: BlockNumber) -> Result<(), GovernorError> {
+                governor::InternalImpl::_set_voting_delay(self, new_voting_delay)
+            }
+
+            fn _set_voting_period(&mut self, new_voting_period: BlockNumber) -> Result<(), GovernorError> {
+                governor::InternalImpl::_set_voting_period(self, new_voting_period)
+            }
+
+            fn _set_proposal_threshold(&mut self, new_proposal_threshold: Balance) -> Result<(), GovernorError> {
+                governor::InternalImpl::_set_proposal_threshold(self, new_proposal_threshold)
+            }
+
+            fn _update_quorum_numerator(&mut self, new_quorum_numerator: u128) -> Result<(), GovernorError> {
+                governor::InternalImpl::_update_quorum_numerator(self, new_quorum_numerator)
+            }
+
+            fn _get_votes(&self, account: &AccountId, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+                governor::InternalImpl::_get_votes(self, account, block_number)
+            }
+
+            fn _get_past_total_supply(&self, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+                governor::InternalImpl::_get_past_total_supply(self, block_number)
+            }
+
+            fn _schedule_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError> {
+                governor::InternalImpl::_schedule_operation(self, transactions, salt)
+            }
+
+            fn _execute_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError> {
+                governor::InternalImpl::_execute_operation(self, transactions, salt)
+            }
+
+            fn _cancel_operation(&mut self, transactions: Vec<Transaction>, salt: [u8; 32]) -> Result<(), GovernorError> {
+                governor::InternalImpl::_cancel_operation(self, transactions, salt)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let governor_impl = syn::parse2::<syn::ItemImpl>(quote!(
+        impl GovernorImpl for #storage_struct_name {}
+    ))
+    .expect("Should parse");
+
+    let mut governor = syn::parse2::<syn::ItemImpl>(quote!(
+        impl Governor for #storage_struct_name {
+            #[ink(message)]
+            fn hash_proposal(&self, transactions: Vec<Transaction>, description_hash: [u8; 32]) -> ProposalId {
+                GovernorImpl::hash_proposal(self, transactions, description_hash)
+            }
+
+            #[ink(message)]
+            fn state(&self, proposal_id: ProposalId) -> Result<ProposalState, GovernorError> {
+                GovernorImpl::state(self, proposal_id)
+            }
+
+            #[ink(message)]
+            fn proposal_snapshot(&self, proposal_id: ProposalId) -> Result<BlockNumber, GovernorError> {
+                GovernorImpl::proposal_snapshot(self, proposal_id)
+            }
+
+            #[ink(message)]
+            fn proposal_deadline(&self, proposal_id: ProposalId) -> Result<BlockNumber, GovernorError> {
+                GovernorImpl::proposal_deadline(self, proposal_id)
+            }
+
+            #[ink(message)]
+            fn proposal_proposer(&self, proposal_id: ProposalId) -> Result<AccountId, GovernorError> {
+                GovernorImpl::proposal_proposer(self, proposal_id)
+            }
+
+            #[ink(message)]
+            fn proposal_votes(&self, proposal_id: ProposalId) -> ProposalVote {
+                GovernorImpl::proposal_votes(self, proposal_id)
+            }
+
+            #[ink(message)]
+            fn has_voted(&self, proposal_id: ProposalId, account: AccountId) -> bool {
+                GovernorImpl::has_voted(self, proposal_id, account)
+            }
+
+            #[ink(message)]
+            fn token(&self) -> Option<AccountId> {
+                GovernorImpl::token(self)
+            }
+
+            #[ink(message)]
+            fn timelock(&self) -> Option<AccountId> {
+                GovernorImpl::timelock(self)
+            }
+
+            #[ink(message)]
+            fn voting_delay(&self) -> BlockNumber {
+                GovernorImpl::voting_delay(self)
+            }
+
+            #[ink(message)]
+            fn voting_period(&self) -> BlockNumber {
+                GovernorImpl::voting_period(self)
+            }
+
+            #[ink(message)]
+            fn proposal_threshold(&self) -> Balance {
+                GovernorImpl::proposal_threshold(self)
+            }
+
+            #[ink(message)]
+            fn quorum(&self, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+                GovernorImpl::quorum(self, block_number)
+            }
+
+            #[ink(message)]
+            fn quorum_numerator(&self) -> u128 {
+                GovernorImpl::quorum_numerator(self)
+            }
+
+            #[ink(message)]
+            fn quorum_denominator(&self) -> u128 {
+                GovernorImpl::quorum_denominator(self)
+            }
+
+            #[ink(message)]
+            fn get_votes(&self, account: AccountId, block_number: BlockNumber) -> Result<Balance, GovernorError> {
+                GovernorImpl::get_votes(self, account, block_number)
+            }
+
+            #[ink(message)]
+            fn propose(&mut self, transactions: Vec<Transaction>, description: String) -> Result<ProposalId, GovernorError> {
+                GovernorImpl::propose(self, transactions, description)
+            }
+
+            #[ink(message)]
+            fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType) -> Result<Balance, GovernorError> {
+                GovernorImpl::cast_vote(self, proposal_id, support)
+            }
+
+            #[ink(message)]
+            fn queue(&mut self, transactions: Vec<Transaction>, description_hash: [u8; 32]) -> Result<ProposalId, GovernorError> {
+                GovernorImpl::queue(self, transactions, description_hash)
+            }
+
+            #[ink(message)]
+            fn execute(&mut self, transactions: Vec<Transaction>, description_hash: [u8; 32]) -> Result<ProposalId, GovernorError> {
+                GovernorImpl::execute(self, transactions, description_hash)
+            }
+
+            #[ink(message)]
+            fn cancel(&mut self, transactions: Vec<Transaction>, description_hash: [u8; 32]) -> Result<ProposalId, GovernorError> {
+                GovernorImpl::cancel(self, transactions, description_hash)
+            }
+
+            #[ink(message)]
+            fn set_voting_delay(&mut self, new_voting_delay: BlockNumber) -> Result<(), GovernorError> {
+                GovernorImpl::set_voting_delay(self, new_voting_delay)
+            }
+
+            #[ink(message)]
+            fn set_voting_period(&mut self, new_voting_period: BlockNumber) -> Result<(), GovernorError> {
+                GovernorImpl::set_voting_period(self, new_voting_period)
+            }
+
+            #[ink(message)]
+            fn set_proposal_threshold(&mut self, new_proposal_threshold: Balance) -> Result<(), GovernorError> {
+                GovernorImpl::set_proposal_threshold(self, new_proposal_threshold)
+            }
+
+            #[ink(message)]
+            fn update_quorum_numerator(&mut self, new_quorum_numerator: u128) -> Result<(), GovernorError> {
+                GovernorImpl::update_quorum_numerator(self, new_quorum_numerator)
+            }
+        }
+    ))
+    .expect("Should parse");
+
+    let import = syn::parse2::<syn::ItemUse>(quote!(
+        use openbrush::contracts::governor::*;
+    ))
+    .expect("Should parse");
+    impl_args.imports.insert("Governor", import);
+    impl_args.vec_import();
+
+    override_functions("governor::Internal", &mut internal, impl_args.map);
+    override_functions("Governor", &mut governor, impl_args.map);
+
+    impl_args.items.push(syn::Item::Impl(internal_impl));
+    impl_args.items.push(syn::Item::Impl(internal));
+    impl_args.items.push(syn::Item::Impl(governor_impl));
+    impl_args.items.push(syn::Item::Impl(governor));
+}
+
 pub(crate) fn impl_proxy(impl_args: &mut ImplArgs) {
     let storage_struct_name = impl_args.contract_name();
     let internal_impl = syn::parse2::<syn::ItemImpl>(quote!(
@@ -2642,3 +3933,24 @@ fn override_functions(trait_name: &str, implementation: &mut syn::ItemImpl, map:
         }
     }
 }
+
+// inserts the call of `hook` with the same arguments at the beginning of the `fn_name` method
+fn call_before(implementation: &mut syn::ItemImpl, fn_name: &str, hook: TokenStream) {
+    for item in implementation.items.iter_mut() {
+        if let syn::ImplItem::Method(method) = item {
+            if method.sig.ident == fn_name {
+                let args = method.sig.inputs.iter().filter_map(|arg| {
+                    match arg {
+                        syn::FnArg::Typed(pat_type) => Some(pat_type.pat.clone()),
+                        syn::FnArg::Receiver(_) => None,
+                    }
+                });
+                let call = syn::parse2::<syn::Stmt>(quote!(
+                    #hook(self, #(#args),*)?;
+                ))
+                .expect("Should parse");
+                method.block.stmts.insert(0, call);
+            }
+        }
+    }
+}
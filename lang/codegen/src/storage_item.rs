@@ -37,7 +37,51 @@ use syn::{
     Fields,
 };
 
-fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>, Vec<Option<TokenStream>>) {
+// Storage types which are placed under their own `ManualKey`,
+// with the position of the generic argument after which the key is inserted
+const MANUAL_KEY_TYPES: [(&str, usize); 6] = [
+    ("Mapping", 1),
+    ("MultiMapping", 1),
+    ("IterableMapping", 1),
+    ("StorageVec", 0),
+    ("StorageSet", 0),
+    ("StorageDeque", 0),
+];
+
+fn manual_key_position(ident: &syn::Ident) -> Option<usize> {
+    MANUAL_KEY_TYPES
+        .iter()
+        .find(|(ty, _)| ident == ty)
+        .map(|(_, position)| *position)
+}
+
+// Entry of `StorageKeys::STORAGE_KEYS`: the path of the field and its storage key
+fn storage_key_entry(structure_name: &str, field_name: &str, key_name: &syn::Ident) -> TokenStream {
+    quote! {
+        ::openbrush::storage::StorageKeyEntry::Key(
+            ::openbrush::utils::const_format::concatcp!(::core::module_path!(), "::", #structure_name, "::", #field_name),
+            #key_name,
+        )
+    }
+}
+
+// Entry of `StorageKeys::STORAGE_KEYS`: the path of the field and the storage keys of its type
+fn nested_storage_keys_entry(structure_name: &str, field_name: &str, ty: &syn::Type) -> TokenStream {
+    quote! {
+        ::openbrush::storage::StorageKeyEntry::Nested(
+            ::openbrush::utils::const_format::concatcp!(::core::module_path!(), "::", #structure_name, "::", #field_name),
+            {
+                use ::openbrush::storage::NoStorageKeys as _;
+                ::openbrush::storage::StorageKeysProbe::<#ty>::STORAGE_KEYS
+            },
+        )
+    }
+}
+
+// Storage key constants and the entries of `StorageKeys::STORAGE_KEYS` for them
+type StorageKeysItems = (Vec<Option<TokenStream>>, Vec<Option<TokenStream>>);
+
+fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>, StorageKeysItems) {
     fields
         .iter()
         .map(|field| {
@@ -66,8 +110,9 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
                 let storage_key = quote! {
                     pub const #key_name: u32 = ::openbrush::storage_unique_key!(#structure_name, #field_name);
                 };
+                let entry = storage_key_entry(structure_name, &field_name, &key_name);
 
-                (new_field, Some(storage_key))
+                (new_field, (Some(storage_key), Some(entry)))
             } else {
                 let mut new_field = field.clone();
                 let span = field.ty.span();
@@ -79,9 +124,9 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
                     field_name.to_uppercase()
                 );
 
-                let is_mapping = if let syn::Type::Path(path) = &field.ty {
+                let has_manual_key = if let syn::Type::Path(path) = &field.ty {
                     if let Some(segment) = path.path.segments.last() {
-                        segment.ident == "Mapping" || segment.ident == "MultiMapping"
+                        manual_key_position(&segment.ident).is_some()
                     } else {
                         false
                     }
@@ -91,10 +136,10 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
 
                 if let syn::Type::Path(path) = &mut new_field.ty {
                     if let Some(segment) = path.path.segments.last_mut() {
-                        if segment.ident == "Mapping" || segment.ident == "MultiMapping" {
+                        if let Some(position) = manual_key_position(&segment.ident) {
                             let mut args = segment.arguments.clone();
                             if let syn::PathArguments::AngleBracketed(args) = &mut args {
-                                if let Some(syn::GenericArgument::Type(ty)) = args.args.iter_mut().nth(1) {
+                                if let Some(syn::GenericArgument::Type(ty)) = args.args.iter_mut().nth(position) {
                                     *ty = syn::Type::Verbatim(quote_spanned!(span =>
                                         #ty, ::ink::storage::traits::ManualKey<#key_name>
                                     ));
@@ -105,20 +150,35 @@ fn wrap_upgradeable_fields(structure_name: &str, fields: Fields) -> (Vec<Field>,
                     }
                 }
 
-                let storage_key = if is_mapping {
-                    Some(quote! {
+                if has_manual_key {
+                    let storage_key = quote! {
                         pub const #key_name: u32 = ::openbrush::storage_unique_key!(#structure_name, #field_name);
-                    })
+                    };
+                    let entry = storage_key_entry(structure_name, &field_name, &key_name);
+
+                    (new_field, (Some(storage_key), Some(entry)))
                 } else {
-                    None
-                };
+                    let entry = nested_storage_keys_entry(structure_name, &field_name, &field.ty);
 
-                (new_field, storage_key)
+                    (new_field, (None, Some(entry)))
+                }
             }
         })
         .unzip()
 }
 
+fn generate_storage_keys_impl(s: &synstructure::Structure, entries: Vec<Option<TokenStream>>) -> TokenStream {
+    let ident = s.ast().ident.clone();
+    let (impl_generics, ty_generics, where_clause) = s.ast().generics.split_for_impl();
+    let entries = entries.into_iter().flatten();
+
+    quote! {
+        impl #impl_generics ::openbrush::storage::StorageKeys for #ident #ty_generics #where_clause {
+            const STORAGE_KEYS: &'static [::openbrush::storage::StorageKeyEntry] = &[#(#entries),*];
+        }
+    }
+}
+
 fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> TokenStream {
     let struct_ident = s.ast().ident.clone();
     let vis = s.ast().vis.clone();
@@ -126,7 +186,9 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
     let attrs = s.ast().attrs.clone();
     let (_, _, where_closure) = s.ast().generics.split_for_impl();
 
-    let (fields, storage_keys) = wrap_upgradeable_fields(struct_ident.to_string().as_str(), struct_item.fields.clone());
+    let (fields, (storage_keys, entries)) =
+        wrap_upgradeable_fields(struct_ident.to_string().as_str(), struct_item.fields.clone());
+    let storage_keys_impl = generate_storage_keys_impl(s, entries);
 
     match struct_item.fields {
         Fields::Unnamed(_) => {
@@ -137,6 +199,8 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
                 );
 
                 #(#storage_keys)*
+
+                #storage_keys_impl
             }
         }
         _ => {
@@ -147,6 +211,8 @@ fn generate_struct(s: &synstructure::Structure, struct_item: DataStruct) -> Toke
                 }
 
                 #(#storage_keys)*
+
+                #storage_keys_impl
             }
         }
     }
@@ -159,34 +225,41 @@ fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStrea
     let types = s.ast().generics.clone();
     let (_, _, where_closure) = s.ast().generics.split_for_impl();
     let mut all_storage_keys: Vec<Option<TokenStream>> = vec![];
+    let mut all_entries: Vec<Option<TokenStream>> = vec![];
 
-    let variants = enum_item.variants.into_iter().map(|variant| {
-        let attrs = variant.attrs;
-        let variant_ident = &variant.ident;
-        let discriminant = if let Some((eq, expr)) = variant.discriminant {
-            quote! { #eq #expr}
-        } else {
-            quote! {}
-        };
-
-        let (fields, storage_keys) = wrap_upgradeable_fields(
-            format!("{}_{}", enum_ident, variant_ident).as_str(),
-            variant.fields.clone(),
-        );
-
-        let fields = match variant.fields {
-            Fields::Named(_) => quote! { { #(#fields),* } },
-            Fields::Unnamed(_) => quote! { ( #(#fields),* ) },
-            Fields::Unit => quote! {},
-        };
-
-        all_storage_keys.extend(storage_keys);
-
-        quote! {
-            #(#attrs)*
-            #variant_ident #fields #discriminant,
-        }
-    });
+    let variants: Vec<_> = enum_item
+        .variants
+        .into_iter()
+        .map(|variant| {
+            let attrs = variant.attrs;
+            let variant_ident = &variant.ident;
+            let discriminant = if let Some((eq, expr)) = variant.discriminant {
+                quote! { #eq #expr}
+            } else {
+                quote! {}
+            };
+
+            let (fields, (storage_keys, entries)) = wrap_upgradeable_fields(
+                format!("{}_{}", enum_ident, variant_ident).as_str(),
+                variant.fields.clone(),
+            );
+
+            let fields = match variant.fields {
+                Fields::Named(_) => quote! { { #(#fields),* } },
+                Fields::Unnamed(_) => quote! { ( #(#fields),* ) },
+                Fields::Unit => quote! {},
+            };
+
+            all_storage_keys.extend(storage_keys);
+            all_entries.extend(entries);
+
+            quote! {
+                #(#attrs)*
+                #variant_ident #fields #discriminant,
+            }
+        })
+        .collect();
+    let storage_keys_impl = generate_storage_keys_impl(s, all_entries);
 
     quote! {
         #(#attrs)*
@@ -195,6 +268,8 @@ fn generate_enum(s: &synstructure::Structure, enum_item: DataEnum) -> TokenStrea
         }
 
         #(#all_storage_keys)*
+
+        #storage_keys_impl
     }
 }
 
@@ -433,10 +433,22 @@ synstructure::decl_attribute!(
     /// Fields, that you want setters to be generated, should be marked by `#[set]` attribute.
     /// The name of the accessor message will be concatenation of `get/set` + `_` + field's name.
     ///
+    /// Accessors of `#[lazy]` fields load and store the value of the field.
+    /// Accessors of `Mapping<K, V>` fields take the key: `get_x(key: K) -> Option<V>` and `set_x(key: K, value: V)`.
+    ///
+    /// Setters can be wrapped with modifiers: `#[set(modifiers(ownable::only_owner), error = OwnableError)]`.
+    /// Such setters return `Result<(), Error>`. The default modifiers and error type of all setters can be
+    /// specified in the macro's attribute after the trait: `#[openbrush::accessors(SomeStructAccessors: Storage<ownable::Data>,
+    /// modifiers(ownable::only_owner), error = OwnableError)]`. Bounds after the trait's name are added to the trait,
+    /// so the modifiers can access their storage.
+    ///
     /// # Example:
     /// ```skip
     ///
-    /// use openbrush::traits::Storage;
+    /// use openbrush::{
+    ///     storage::Mapping,
+    ///     traits::Storage,
+    /// };
     ///
     /// #[openbrush::accessors(SomeStructGetters)]
     /// #[derive(Default)]
@@ -447,6 +459,9 @@ synstructure::decl_attribute!(
     ///     b: u32,
     ///     #[set]
     ///     c: u32,
+    ///     #[get]
+    ///     #[set]
+    ///     config: Mapping<u32, u128>,
     /// }
     ///
     /// #[openbrush::contract]
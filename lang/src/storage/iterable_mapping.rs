@@ -0,0 +1,505 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::{
+    RawMapping,
+    RefGuard,
+    TypeGuard,
+    ValueGuard,
+};
+use core::marker::PhantomData;
+use ink::{
+    prelude::vec::Vec,
+    primitives::Key,
+    storage::traits::{
+        AutoKey,
+        Packed,
+        Storable,
+        StorableHint,
+        StorageKey,
+    },
+};
+use scale::{
+    Error,
+    Input,
+    Output,
+};
+
+/// A mapping that keeps an index of its keys alongside the values. The mapping provides
+/// iteration functionality over all keys and values.
+///
+/// Insertion and removal are O(1), the removed key is replaced with the last key in the index,
+/// so the order of keys is not preserved after removal.
+pub struct IterableMapping<K, V, KeyType: StorageKey = AutoKey, TGK = RefGuard<K>, TGV = ValueGuard<V>> {
+    #[allow(clippy::type_complexity)]
+    _marker: PhantomData<fn() -> (K, V, KeyType, TGK, TGV)>,
+}
+
+/// Wrapper to store the key as the value of the index without converting it into `K`.
+///
+/// The encoding of the `TypeGuard` type is the same as the encoding of `K`,
+/// so the stored value is read back as `K`.
+struct EncodeOnly<T>(T);
+
+impl<T: scale::Encode> scale::Encode for EncodeOnly<T> {
+    #[inline]
+    fn encode_to<O: Output + ?Sized>(&self, dest: &mut O) {
+        self.0.encode_to(dest)
+    }
+}
+
+impl<T> scale::Decode for EncodeOnly<T> {
+    #[inline]
+    fn decode<I: Input>(_input: &mut I) -> Result<Self, Error> {
+        Err(Error::from("`EncodeOnly` can't be decoded"))
+    }
+}
+
+impl<K, V, KeyType, TGK, TGV> IterableMapping<K, V, KeyType, TGK, TGV>
+where
+    KeyType: StorageKey,
+{
+    #[allow(dead_code)]
+    fn new() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+
+    /// Contains count of keys.
+    /// len: u128,
+    fn len_storage(&self) -> RawMapping<(), u128, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &0))
+    }
+
+    /// Mapping from key to value.
+    /// values: Mapping<K, V>,
+    fn values<'b>(&self) -> RawMapping<<TGK as TypeGuard<'b>>::Type, <TGV as TypeGuard<'b>>::Type, (&Key, &u32)>
+    where
+        TGK: TypeGuard<'b>,
+        TGV: TypeGuard<'b>,
+    {
+        RawMapping::new((&KeyType::KEY, &1))
+    }
+
+    /// Mapping from key to its index.
+    /// key_to_index: Mapping<K, u128>,
+    fn key_to_index<'b>(&self) -> RawMapping<<TGK as TypeGuard<'b>>::Type, u128, (&Key, &u32)>
+    where
+        TGK: TypeGuard<'b>,
+    {
+        RawMapping::new((&KeyType::KEY, &2))
+    }
+
+    /// Mapping from index to key.
+    /// index_to_key: Mapping<u128, K>,
+    fn index_to_key(&self) -> RawMapping<&u128, K, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &3))
+    }
+
+    /// Mapping from index to key, which writes the key passed as the `TypeGuard` type.
+    /// index_to_key: Mapping<u128, K>,
+    fn index_to_guarded_key<'b>(&self) -> RawMapping<&u128, EncodeOnly<<TGK as TypeGuard<'b>>::Type>, (&Key, &u32)>
+    where
+        TGK: TypeGuard<'b>,
+    {
+        RawMapping::new((&KeyType::KEY, &3))
+    }
+}
+
+impl<K, V, KeyType, TGK, TGV> Default for IterableMapping<K, V, KeyType, TGK, TGV>
+where
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<K, V, KeyType, TGK, TGV> core::fmt::Debug for IterableMapping<K, V, KeyType, TGK, TGV>
+where
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("IterableMapping").field("key", &KeyType::KEY).finish()
+    }
+}
+
+impl<K, V, KeyType, TGK, TGV> IterableMapping<K, V, KeyType, TGK, TGV>
+where
+    K: Packed,
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Insert the given `value` to the contract storage at `key`.
+    ///
+    /// The `key` is appended to the end of the index if it is not stored yet.
+    pub fn insert<'b>(&'b mut self, key: <TGK as TypeGuard<'b>>::Type, value: &<TGV as TypeGuard<'b>>::Type)
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> TGV: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode + Copy,
+        for<'a> <TGV as TypeGuard<'a>>::Type: Packed,
+    {
+        if !self.contains(key) {
+            let index = self.len();
+            self.index_to_guarded_key().insert(&index, &EncodeOnly(key));
+            self.key_to_index().insert(key, &index);
+            self.len_storage().insert((), &(index + 1));
+        }
+        self.values().insert(key, value)
+    }
+
+    /// Get the `value` at `key` from the contract storage.
+    ///
+    /// Returns `None` if no `value` exists at the given `key`.
+    #[inline]
+    pub fn get<'b>(&self, key: <TGK as TypeGuard<'b>>::Type) -> Option<V>
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode,
+    {
+        RawMapping::<<TGK as TypeGuard<'b>>::Type, V, _>::new((&KeyType::KEY, &1)).get(key)
+    }
+
+    /// Get the index of the `key` from the contract storage.
+    ///
+    /// Returns `None` if no `value` exists at the given `key`.
+    #[inline]
+    pub fn get_index<'b>(&self, key: <TGK as TypeGuard<'b>>::Type) -> Option<u128>
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode,
+    {
+        self.key_to_index().get(key)
+    }
+
+    /// Checks if a value is stored at the given `key` in the contract storage.
+    #[inline]
+    pub fn contains<'b>(&self, key: <TGK as TypeGuard<'b>>::Type) -> bool
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode,
+    {
+        self.key_to_index().contains(key)
+    }
+
+    /// Returns the count of keys stored in the mapping.
+    #[inline]
+    pub fn len(&self) -> u128 {
+        self.len_storage().get(()).unwrap_or_default()
+    }
+
+    /// Checks if no keys are stored in the mapping.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the key at `index` from the contract storage.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn key_at(&self, index: u128) -> Option<K> {
+        self.index_to_key().get(&index)
+    }
+
+    /// Returns up to `limit` pairs of key and value starting from the `start` index.
+    pub fn iter_range(&self, start: u128, limit: u128) -> Vec<(K, V)> {
+        let end = core::cmp::min(start.saturating_add(limit), self.len());
+
+        (start..end)
+            .filter_map(|index| {
+                let key = self.key_at(index)?;
+                let value = RawMapping::<&K, V, _>::new((&KeyType::KEY, &1)).get(&key)?;
+                Some((key, value))
+            })
+            .collect()
+    }
+
+    /// Clears the value at `key` from storage.
+    ///
+    /// The last key of the index is moved to the index of the removed `key`.
+    pub fn remove<'b>(&'b mut self, key: <TGK as TypeGuard<'b>>::Type)
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode + Copy,
+    {
+        let index = match self.get_index(key) {
+            Some(index) => index,
+            None => return,
+        };
+        let last_index = self.len() - 1;
+
+        if last_index != index {
+            let last_key = self
+                .key_at(last_index)
+                .expect("The key under the last index should exist");
+            self.index_to_key().insert(&index, &last_key);
+            RawMapping::<&K, u128, _>::new((&KeyType::KEY, &2)).insert(&last_key, &index);
+        }
+
+        self.index_to_key().remove(&last_index);
+        self.key_to_index().remove(key);
+        RawMapping::<<TGK as TypeGuard<'b>>::Type, V, _>::new((&KeyType::KEY, &1)).remove(key);
+        self.len_storage().insert((), &last_index);
+    }
+}
+
+impl<K, V, KeyType, TGK, TGV> Storable for IterableMapping<K, V, KeyType, TGK, TGV>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: Input>(_input: &mut I) -> Result<Self, Error> {
+        Ok(Default::default())
+    }
+}
+
+impl<K, V, Key, TGK, TGV, InnerKey> StorableHint<Key> for IterableMapping<K, V, InnerKey, TGK, TGV>
+where
+    V: Packed,
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = IterableMapping<K, V, Key, TGK, TGV>;
+    type PreferredKey = InnerKey;
+}
+
+impl<K, V, TGK, TGV, KeyType> StorageKey for IterableMapping<K, V, KeyType, TGK, TGV>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    const KEY: Key = KeyType::KEY;
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink::{
+        metadata::layout::{
+            FieldLayout,
+            Layout,
+            LayoutKey,
+            LeafLayout,
+            RootLayout,
+            StructLayout,
+        },
+        storage::traits::StorageLayout,
+    };
+    use scale_info::{
+        build::Fields,
+        type_params,
+        Path,
+        Type,
+        TypeInfo,
+    };
+
+    impl<K, V, KeyType, TGK, TGV> TypeInfo for IterableMapping<K, V, KeyType, TGK, TGV>
+    where
+        K: TypeInfo + 'static,
+        V: TypeInfo + 'static,
+        TGK: 'static,
+        TGV: 'static,
+        KeyType: StorageKey + 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("IterableMapping", module_path!()))
+                .type_params(type_params![K, V])
+                .composite(Fields::unnamed().field(|f| f.ty::<[(K, V)]>()))
+        }
+    }
+
+    impl<K, V, KeyType, TGK, TGV> StorageLayout for IterableMapping<K, V, KeyType, TGK, TGV>
+    where
+        K: scale_info::TypeInfo + 'static,
+        V: Packed + StorageLayout + scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+        TGK: 'static,
+        TGV: 'static,
+    {
+        fn layout(_: &Key) -> Layout {
+            let key = &KeyType::KEY;
+            let field = |name: &'static str, index: u32, layout: Layout| {
+                FieldLayout::new(name, RawMapping::<(), (), _>::new((key, &index)).layout(key, layout))
+            };
+
+            Layout::Root(RootLayout::new(
+                LayoutKey::from(key),
+                StructLayout::new(
+                    "IterableMapping",
+                    [
+                        field("len", 0, LeafLayout::from_key::<u128>(LayoutKey::from(key)).into()),
+                        field("values", 1, <V as StorageLayout>::layout(key)),
+                        field(
+                            "key_to_index",
+                            2,
+                            LeafLayout::from_key::<u128>(LayoutKey::from(key)).into(),
+                        ),
+                        field(
+                            "index_to_key",
+                            3,
+                            LeafLayout::from_key::<K>(LayoutKey::from(key)).into(),
+                        ),
+                    ],
+                ),
+            ))
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn insert_and_get_work() {
+        let mut mapping: IterableMapping<u128, u128> = IterableMapping::default();
+
+        mapping.insert(&1, &10);
+        mapping.insert(&2, &20);
+        mapping.insert(&1, &11);
+
+        assert_eq!(mapping.get(&1), Some(11));
+        assert_eq!(mapping.get(&2), Some(20));
+        assert_eq!(mapping.get(&3), None);
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(mapping.key_at(0), Some(1));
+        assert_eq!(mapping.key_at(1), Some(2));
+        assert_eq!(mapping.key_at(2), None);
+    }
+
+    #[ink::test]
+    fn remove_swaps_last_key() {
+        let mut mapping: IterableMapping<u128, u128> = IterableMapping::default();
+
+        mapping.insert(&1, &10);
+        mapping.insert(&2, &20);
+        mapping.insert(&3, &30);
+
+        mapping.remove(&1);
+
+        assert!(!mapping.contains(&1));
+        assert_eq!(mapping.get(&1), None);
+        assert_eq!(mapping.len(), 2);
+        assert_eq!(mapping.key_at(0), Some(3));
+        assert_eq!(mapping.get_index(&3), Some(0));
+        assert_eq!(mapping.key_at(2), None);
+
+        mapping.remove(&3);
+        mapping.remove(&2);
+        mapping.remove(&2);
+
+        assert!(mapping.is_empty());
+        assert_eq!(mapping.key_at(0), None);
+    }
+
+    #[ink::test]
+    fn iter_range_works() {
+        let mut mapping: IterableMapping<u128, u128> = IterableMapping::default();
+
+        for key in 0..5 {
+            mapping.insert(&key, &(key * 10));
+        }
+
+        assert_eq!(mapping.iter_range(1, 2), vec![(1, 10), (2, 20)]);
+        assert_eq!(mapping.iter_range(3, 10), vec![(3, 30), (4, 40)]);
+        assert_eq!(mapping.iter_range(5, 10), vec![]);
+        assert_eq!(mapping.iter_range(u128::MAX, u128::MAX), vec![]);
+    }
+
+    pub struct PairKey;
+
+    impl<'a> TypeGuard<'a> for PairKey {
+        type Type = &'a (&'a u32, &'a u64);
+    }
+
+    #[ink::test]
+    fn type_guard_keys_are_read_back() {
+        let mut mapping: IterableMapping<(u32, u64), u128, AutoKey, PairKey> = IterableMapping::default();
+
+        mapping.insert(&(&1, &2), &12);
+        mapping.insert(&(&3, &4), &34);
+        mapping.remove(&(&1, &2));
+
+        assert_eq!(mapping.key_at(0), Some((3, 4)));
+        assert_eq!(mapping.get_index(&(&3, &4)), Some(0));
+        assert_eq!(mapping.iter_range(0, 10), vec![((3, 4), 34)]);
+    }
+
+    #[ink::test]
+    fn layout_reports_prefixes_of_stored_values() {
+        use ink::{
+            metadata::layout::Layout,
+            storage::traits::{
+                ManualKey,
+                StorageLayout,
+            },
+        };
+
+        let mut mapping: IterableMapping<u32, u64, ManualKey<123>> = IterableMapping::default();
+        mapping.insert(&5, &50);
+
+        let layout = <IterableMapping<u32, u64, ManualKey<123>> as StorageLayout>::layout(&123);
+        let fields = match &layout {
+            Layout::Root(root) => {
+                match root.layout() {
+                    Layout::Struct(structure) => structure.fields(),
+                    _ => panic!("The root should contain a struct"),
+                }
+            }
+            _ => panic!("The layout should be a root"),
+        };
+        let prefix = |name: &str| {
+            match fields
+                .iter()
+                .find(|field| *field.name() == name)
+                .map(|field| field.layout())
+            {
+                Some(Layout::Hash(hash)) => hash.strategy().prefix().to_vec(),
+                _ => panic!("The field should be a hash layout"),
+            }
+        };
+
+        assert_eq!(prefix("len"), scale::Encode::encode(&(123u32, 0u32)));
+        assert_eq!(prefix("values"), scale::Encode::encode(&(123u32, 1u32)));
+        assert_eq!(prefix("key_to_index"), scale::Encode::encode(&(123u32, 2u32)));
+        assert_eq!(prefix("index_to_key"), scale::Encode::encode(&(123u32, 3u32)));
+        // The values are stored under the prefix followed by the key
+        assert_eq!(
+            ink::env::get_contract_storage::<_, u64>(&((&123u32, &1u32), &5u32)),
+            Ok(Some(50))
+        );
+        assert_eq!(
+            ink::env::get_contract_storage::<_, u32>(&((&123u32, &3u32), &0u128)),
+            Ok(Some(5))
+        );
+    }
+}
@@ -0,0 +1,431 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+//! Snapshot of the storage layout of the contract and the check of the compatibility between two
+//! snapshots. It helps to verify that the new code hash, which will be set via
+//! `Upgradeable::set_code_hash`, can work with the storage of the old code.
+
+use ink::{
+    metadata::layout::{
+        Layout,
+        LayoutKey,
+        RootLayout,
+    },
+    primitives::Key,
+    storage::traits::{
+        StorageKey,
+        StorageLayout,
+    },
+};
+use scale_info::{
+    form::MetaForm,
+    Field,
+    MetaType,
+    Registry,
+    TypeDef,
+    TypeInfo,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use std::collections::BTreeMap;
+
+/// The cell of the storage: the path of the field, the resolved storage key under which
+/// the field is stored and the type of the field.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutEntry {
+    pub path: String,
+    pub key: Key,
+    /// SCALE type id of the field in the registry of the snapshot.
+    /// It depends on the order of the fields, so the `type_name` is used for the comparison.
+    pub type_id: u32,
+    pub type_name: String,
+    /// Type of the keys of the mapping which stores the field, `None` if the field is not stored in a mapping.
+    /// The storage layout of the mapping doesn't contain it, so it is taken from the type of the field.
+    #[serde(default)]
+    pub key_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LayoutSnapshot {
+    pub entries: Vec<LayoutEntry>,
+}
+
+/// The change between two snapshots which breaks the storage of the old version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LayoutChange {
+    /// The field was removed or renamed.
+    RemovedField { path: String, key: Key },
+    /// The field is stored under another key.
+    ChangedKey { path: String, old_key: Key, new_key: Key },
+    /// The field is stored under the same key, but the type is different.
+    ChangedType {
+        path: String,
+        old_type: String,
+        new_type: String,
+    },
+    /// The field is stored in a mapping under the same key, but the type of the keys of the mapping is different.
+    ChangedKeyType {
+        path: String,
+        old_type: Option<String>,
+        new_type: Option<String>,
+    },
+    /// The new field is stored under the key of the removed field.
+    ReusedKey {
+        key: Key,
+        old_path: String,
+        new_path: String,
+    },
+}
+
+impl LayoutSnapshot {
+    /// Collects all fields of the contract storage `T`.
+    pub fn of<T: StorageLayout + StorageKey + TypeInfo + 'static>() -> Self {
+        let layout = Layout::Root(RootLayout::new(LayoutKey::from(&T::KEY), T::layout(&T::KEY)));
+        let mut collector = Collector {
+            registry: Registry::new(),
+            entries: vec![],
+        };
+        collector.collect(&layout, "", Some(MetaType::new::<T>()), None);
+
+        Self {
+            entries: collector.entries,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("Snapshot is always serializable")
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Returns the changes of the `new` snapshot which are incompatible with `self`.
+    /// New fields under unused keys are compatible.
+    pub fn diff(&self, new: &LayoutSnapshot) -> Vec<LayoutChange> {
+        let new_fields: BTreeMap<&str, &LayoutEntry> =
+            new.entries.iter().map(|entry| (entry.path.as_str(), entry)).collect();
+        let old_fields: BTreeMap<&str, &LayoutEntry> =
+            self.entries.iter().map(|entry| (entry.path.as_str(), entry)).collect();
+        let mut changes = vec![];
+
+        for old in self.entries.iter() {
+            match new_fields.get(old.path.as_str()) {
+                None => {
+                    changes.push(LayoutChange::RemovedField {
+                        path: old.path.clone(),
+                        key: old.key,
+                    })
+                }
+                Some(new) if new.key != old.key => {
+                    changes.push(LayoutChange::ChangedKey {
+                        path: old.path.clone(),
+                        old_key: old.key,
+                        new_key: new.key,
+                    })
+                }
+                Some(new) if new.type_name != old.type_name => {
+                    changes.push(LayoutChange::ChangedType {
+                        path: old.path.clone(),
+                        old_type: old.type_name.clone(),
+                        new_type: new.type_name.clone(),
+                    })
+                }
+                Some(new) if new.key_type != old.key_type => {
+                    changes.push(LayoutChange::ChangedKeyType {
+                        path: old.path.clone(),
+                        old_type: old.key_type.clone(),
+                        new_type: new.key_type.clone(),
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+
+        for new in new
+            .entries
+            .iter()
+            .filter(|entry| !old_fields.contains_key(entry.path.as_str()))
+        {
+            let removed = self
+                .entries
+                .iter()
+                .find(|old| old.key == new.key && !new_fields.contains_key(old.path.as_str()));
+
+            if let Some(old) = removed {
+                changes.push(LayoutChange::ReusedKey {
+                    key: new.key,
+                    old_path: old.path.clone(),
+                    new_path: new.path.clone(),
+                })
+            }
+        }
+
+        changes
+    }
+}
+
+/// Returns the JSON snapshot of the storage layout of the contract `T`.
+pub fn snapshot<T: StorageLayout + StorageKey + TypeInfo + 'static>() -> String {
+    LayoutSnapshot::of::<T>().to_json()
+}
+
+/// Compares two JSON snapshots and returns the changes which are incompatible with the `old` one.
+pub fn diff(old: &str, new: &str) -> Result<Vec<LayoutChange>, serde_json::Error> {
+    Ok(LayoutSnapshot::from_json(old)?.diff(&LayoutSnapshot::from_json(new)?))
+}
+
+struct Collector {
+    registry: Registry,
+    entries: Vec<LayoutEntry>,
+}
+
+impl Collector {
+    /// `ty` is the type described by the `layout`, if it is known. It is used to find the key types of mappings.
+    fn collect(&mut self, layout: &Layout, path: &str, ty: Option<MetaType>, key_type: Option<&str>) {
+        match layout {
+            Layout::Leaf(leaf) => {
+                let type_id = self.registry.register_type(leaf.ty()).id;
+                self.entries.push(LayoutEntry {
+                    path: path.to_string(),
+                    key: *leaf.key().key(),
+                    type_id,
+                    type_name: type_name(leaf.ty()),
+                    key_type: key_type.map(str::to_string),
+                })
+            }
+            Layout::Root(root) => {
+                // `Mapping` and `Lazy` are roots of their values, the contract is the root of itself
+                let key_type = ty.and_then(|ty| type_param(&ty, "K")).map(|key| type_name(&key));
+                let value = ty.and_then(|ty| type_param(&ty, "V").or(Some(ty)));
+                self.collect(root.layout(), path, value, key_type.as_deref())
+            }
+            Layout::Hash(hash) => self.collect(hash.layout(), path, None, key_type),
+            Layout::Array(array) => self.collect(array.layout(), &format!("{}[]", path), None, key_type),
+            Layout::Struct(structure) => {
+                let fields = ty.map(|ty| ty.type_info().type_def).and_then(|def| {
+                    match def {
+                        TypeDef::Composite(composite) => Some(composite.fields),
+                        _ => None,
+                    }
+                });
+                for field in structure.fields() {
+                    let field_ty = fields.as_ref().and_then(|fields| field_type(fields, field.name()));
+                    self.collect(field.layout(), &join(path, field.name()), field_ty, key_type)
+                }
+            }
+            Layout::Enum(enumeration) => {
+                let variants = ty.map(|ty| ty.type_info().type_def).and_then(|def| {
+                    match def {
+                        TypeDef::Variant(variant) => Some(variant.variants),
+                        _ => None,
+                    }
+                });
+                for variant in enumeration.variants().values() {
+                    let variant_path = join(path, variant.name());
+                    let variant_fields = variants
+                        .as_ref()
+                        .and_then(|variants| variants.iter().find(|v| v.name == *variant.name()))
+                        .map(|v| &v.fields);
+                    for field in variant.fields() {
+                        let field_ty = variant_fields.and_then(|fields| field_type(fields, field.name()));
+                        self.collect(field.layout(), &join(&variant_path, field.name()), field_ty, key_type)
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Returns the type of the generic parameter `name` of `ty`.
+fn type_param(ty: &MetaType, name: &str) -> Option<MetaType> {
+    ty.type_info()
+        .type_params
+        .iter()
+        .find(|param| param.name == name)
+        .and_then(|param| param.ty)
+}
+
+/// Returns the type of the field `name`, unnamed fields are named by their index in the layout.
+fn field_type(fields: &[Field<MetaForm>], name: &str) -> Option<MetaType> {
+    fields
+        .iter()
+        .enumerate()
+        .find(|(index, field)| {
+            match field.name {
+                Some(field_name) => field_name == name,
+                None => index.to_string() == name,
+            }
+        })
+        .map(|(_, field)| field.ty)
+}
+
+fn join(path: &str, name: &str) -> String {
+    if path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}::{}", path, name)
+    }
+}
+
+/// Human readable name of the type, which includes the generic arguments.
+fn type_name(ty: &MetaType) -> String {
+    let info = ty.type_info();
+    let params: Vec<String> = info
+        .type_params
+        .iter()
+        .filter_map(|param| param.ty.as_ref().map(type_name))
+        .collect();
+
+    if !info.path.segments.is_empty() {
+        let path = info.path.segments.join("::");
+        return if params.is_empty() {
+            path
+        } else {
+            format!("{}<{}>", path, params.join(", "))
+        }
+    }
+
+    match &info.type_def {
+        TypeDef::Primitive(primitive) => format!("{:?}", primitive).to_lowercase(),
+        TypeDef::Sequence(sequence) => format!("Vec<{}>", type_name(&sequence.type_param)),
+        TypeDef::Array(array) => format!("[{}; {}]", type_name(&array.type_param), array.len),
+        TypeDef::Tuple(tuple) => {
+            let fields: Vec<String> = tuple.fields.iter().map(type_name).collect();
+            format!("({})", fields.join(", "))
+        }
+        TypeDef::Compact(compact) => format!("Compact<{}>", type_name(&compact.type_param)),
+        TypeDef::BitSequence(_) => "BitVec".to_string(),
+        TypeDef::Composite(_) | TypeDef::Variant(_) => "<unnamed>".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::{
+        primitives::AccountId,
+        storage::{
+            traits::ManualKey,
+            Lazy,
+            Mapping,
+        },
+    };
+
+    #[ink::storage_item]
+    struct V1 {
+        paused: bool,
+        owner: Lazy<AccountId, ManualKey<1>>,
+        balances: Mapping<AccountId, u128, ManualKey<2>>,
+        allowances: Mapping<(AccountId, AccountId), u128, ManualKey<3>>,
+    }
+
+    #[ink::storage_item]
+    struct V2 {
+        paused: bool,
+        owner: Lazy<AccountId, ManualKey<1>>,
+        balances: Mapping<AccountId, u64, ManualKey<2>>,
+        fees: Mapping<AccountId, u128, ManualKey<3>>,
+        supply: Lazy<u128, ManualKey<4>>,
+    }
+
+    #[ink::storage_item]
+    struct V3 {
+        paused: bool,
+        owner: Lazy<AccountId, ManualKey<1>>,
+        balances: Mapping<u32, u128, ManualKey<2>>,
+        allowances: Mapping<(AccountId, AccountId), u128, ManualKey<3>>,
+    }
+
+    #[test]
+    fn snapshot_works() {
+        let snapshot = LayoutSnapshot::of::<V1>();
+
+        assert_eq!(
+            snapshot
+                .entries
+                .iter()
+                .map(|entry| {
+                    (
+                        entry.path.as_str(),
+                        entry.key,
+                        entry.type_name.as_str(),
+                        entry.key_type.as_deref(),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            vec![
+                ("paused", 0, "bool", None),
+                ("owner", 1, "ink_primitives::types::AccountId", None),
+                ("balances", 2, "u128", Some("ink_primitives::types::AccountId")),
+                (
+                    "allowances",
+                    3,
+                    "u128",
+                    Some("(ink_primitives::types::AccountId, ink_primitives::types::AccountId)")
+                ),
+            ]
+        );
+        assert_eq!(LayoutSnapshot::from_json(&snapshot.to_json()).unwrap(), snapshot);
+    }
+
+    #[test]
+    fn same_layout_is_compatible() {
+        assert_eq!(diff(&snapshot::<V1>(), &snapshot::<V1>()).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn diff_reports_incompatible_changes() {
+        assert_eq!(
+            diff(&snapshot::<V1>(), &snapshot::<V2>()).unwrap(),
+            vec![
+                LayoutChange::ChangedType {
+                    path: "balances".to_string(),
+                    old_type: "u128".to_string(),
+                    new_type: "u64".to_string(),
+                },
+                LayoutChange::RemovedField {
+                    path: "allowances".to_string(),
+                    key: 3,
+                },
+                LayoutChange::ReusedKey {
+                    key: 3,
+                    old_path: "allowances".to_string(),
+                    new_path: "fees".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_reports_changed_key_type() {
+        assert_eq!(
+            diff(&snapshot::<V1>(), &snapshot::<V3>()).unwrap(),
+            vec![LayoutChange::ChangedKeyType {
+                path: "balances".to_string(),
+                old_type: Some("ink_primitives::types::AccountId".to_string()),
+                new_type: Some("u32".to_string()),
+            }]
+        );
+    }
+}
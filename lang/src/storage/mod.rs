@@ -20,13 +20,31 @@
 // WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
 
 use core::marker::PhantomData;
+mod iterable_mapping;
+#[cfg(feature = "std")]
+pub mod layout;
 mod mapping;
 mod multi_mapping;
 mod raw_mapping;
+mod storage_deque;
+mod storage_keys;
+mod storage_set;
+mod storage_vec;
 
+pub use iterable_mapping::IterableMapping;
 pub use mapping::Mapping;
 pub use multi_mapping::MultiMapping;
 pub use raw_mapping::RawMapping;
+pub use storage_deque::StorageDeque;
+pub use storage_keys::{
+    assert_unique_storage_keys,
+    NoStorageKeys,
+    StorageKeyEntry,
+    StorageKeys,
+    StorageKeysProbe,
+};
+pub use storage_set::StorageSet;
+pub use storage_vec::StorageVec;
 
 pub trait TypeGuard<'a> {
     type Type: 'a;
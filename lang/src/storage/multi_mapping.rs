@@ -27,6 +27,7 @@ use super::{
 use crate::storage::RawMapping;
 use core::marker::PhantomData;
 use ink::{
+    prelude::vec::Vec,
     primitives::Key,
     storage::traits::{
         AutoKey,
@@ -119,6 +120,9 @@ where
     V: Packed,
     KeyType: StorageKey,
 {
+    /// Maximum number of values removed by one call of `remove_all`.
+    pub const REMOVE_ALL_BATCH_SIZE: u128 = 100;
+
     /// Insert the given `value` to the contract storage at `key`.
     pub fn insert<'b>(&'b mut self, key: <TGK as TypeGuard<'b>>::Type, value: &<TGV as TypeGuard<'b>>::Type)
     where
@@ -162,6 +166,18 @@ where
         RawMapping::<IndexToValue<TGK>, V, _>::new((&KeyType::KEY, &2)).get(&(key, index))
     }
 
+    /// Returns up to `limit` values stored under the `key` starting from the `start` index.
+    pub fn values<'b>(&'b self, key: <TGK as TypeGuard<'b>>::Type, start: u128, limit: u128) -> Vec<V>
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> TGV: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode + Copy,
+    {
+        let end = core::cmp::min(start.saturating_add(limit), self.count(key));
+
+        (start..end).filter_map(|index| self.get_value(key, &index)).collect()
+    }
+
     /// Get the `index` of (`key`, `value`) from the contract storage.
     ///
     /// Returns `None` if no `value` exists for the given `key`.
@@ -285,6 +301,62 @@ where
         self.swap_and_remove(key, &value.into(), index);
     }
 
+    /// Clears up to `REMOVE_ALL_BATCH_SIZE` values at `key` from storage, starting from the last one.
+    ///
+    /// Returns the count of values that remain under the `key`, call it again until it returns zero
+    /// to remove all values.
+    pub fn remove_all<'b>(&'b mut self, key: <TGK as TypeGuard<'b>>::Type) -> u128
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> TGV: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode + Copy,
+        for<'a> <TGV as TypeGuard<'a>>::Type: Packed + From<V>,
+    {
+        let count = self.count(key);
+        let remaining = count.saturating_sub(Self::REMOVE_ALL_BATCH_SIZE);
+
+        for index in (remaining..count).rev() {
+            if let Some(value) = self.get_value(key, &index) {
+                self.value_to_index().remove(&(key, &value.into()));
+            }
+            self.index_to_value().remove(&(key, &index));
+        }
+
+        if remaining == 0 {
+            self.key_count().remove(key);
+        } else {
+            self.key_count().insert(key, &remaining);
+        }
+        remaining
+    }
+
+    /// Swaps the values at indexes `i` and `j` of the `key`.
+    ///
+    /// Does nothing if any of indexes is out of bounds.
+    pub fn swap_values<'b>(&'b mut self, key: <TGK as TypeGuard<'b>>::Type, i: &u128, j: &u128)
+    where
+        for<'a> TGK: TypeGuard<'a>,
+        for<'a> TGV: TypeGuard<'a>,
+        for<'a> <TGK as TypeGuard<'a>>::Type: scale::Encode + Copy,
+        for<'a> <TGV as TypeGuard<'a>>::Type: Packed + From<V>,
+    {
+        if i == j {
+            return
+        }
+
+        let (value_i, value_j) = match (self.get_value(key, i), self.get_value(key, j)) {
+            (Some(value_i), Some(value_j)) => (value_i, value_j),
+            _ => return,
+        };
+        let value_i: <TGV as TypeGuard<'_>>::Type = value_i.into();
+        let value_j: <TGV as TypeGuard<'_>>::Type = value_j.into();
+
+        self.index_to_value().insert(&(key, i), &value_j);
+        self.index_to_value().insert(&(key, j), &value_i);
+        self.value_to_index().insert(&(key, &value_j), i);
+        self.value_to_index().insert(&(key, &value_i), j);
+    }
+
     fn swap_and_remove<'b>(
         &'b mut self,
         key: <TGK as TypeGuard<'b>>::Type,
@@ -490,4 +562,57 @@ mod tests {
         assert_eq!(mapping.contains_value(&1, &1), true);
         assert_eq!(mapping.contains_value(&1, &3), false);
     }
+
+    #[ink::test]
+    fn values_works() {
+        let mut mapping: MultiMapping<u128, u128> = MultiMapping::default();
+        for value in 0..5 {
+            mapping.insert(&1, &value);
+        }
+
+        assert_eq!(mapping.values(&1, 1, 2), vec![1, 2]);
+        assert_eq!(mapping.values(&1, 3, 10), vec![3, 4]);
+        assert_eq!(mapping.values(&1, 5, 10), vec![]);
+        assert_eq!(mapping.values(&2, 0, 10), vec![]);
+    }
+
+    #[ink::test]
+    fn remove_all_works() {
+        type Mapping = MultiMapping<u128, u128>;
+        let mut mapping: Mapping = MultiMapping::default();
+        let batch = Mapping::REMOVE_ALL_BATCH_SIZE;
+        for value in 0..batch + 5 {
+            mapping.insert(&1, &value);
+        }
+        mapping.insert(&2, &1);
+
+        assert_eq!(mapping.remove_all(&1), 5);
+        assert_eq!(mapping.count(&1), 5);
+        assert!(!mapping.contains_value(&1, &5));
+        assert_eq!(mapping.get_value(&1, &4), Some(4));
+
+        assert_eq!(mapping.remove_all(&1), 0);
+        assert!(!mapping.contains(&1));
+        assert!(!mapping.contains_value(&1, &0));
+        assert_eq!(mapping.count(&2), 1);
+
+        mapping.insert(&1, &7);
+        assert_eq!(mapping.get_index(&1, &7), Some(0));
+    }
+
+    #[ink::test]
+    fn swap_values_works() {
+        let mut mapping: MultiMapping<u128, u128> = MultiMapping::default();
+        mapping.insert(&1, &10);
+        mapping.insert(&1, &20);
+        mapping.insert(&1, &30);
+
+        mapping.swap_values(&1, &0, &2);
+        assert_eq!(mapping.values(&1, 0, 10), vec![30, 20, 10]);
+        assert_eq!(mapping.get_index(&1, &30), Some(0));
+        assert_eq!(mapping.get_index(&1, &10), Some(2));
+
+        mapping.swap_values(&1, &0, &3);
+        assert_eq!(mapping.values(&1, 0, 10), vec![30, 20, 10]);
+    }
 }
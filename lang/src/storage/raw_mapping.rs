@@ -102,3 +102,37 @@ where
         (self.prefix, key)
     }
 }
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink::{
+        metadata::layout::{
+            CryptoHasher,
+            HashLayout,
+            HashingStrategy,
+            Layout,
+            LayoutKey,
+        },
+        prelude::vec::Vec,
+    };
+
+    impl<K, V, T> RawMapping<K, V, T>
+    where
+        T: scale::Encode,
+    {
+        /// Returns the layout of the values stored under the prefix, `layout` is the layout of one value.
+        ///
+        /// `root_key` is the key of the storage field which owns the mapping.
+        pub fn layout<L: Into<Layout>>(&self, root_key: &Key, layout: L) -> Layout {
+            Layout::Hash(HashLayout::new(
+                LayoutKey::from(root_key),
+                HashingStrategy::new(
+                    CryptoHasher::Blake2x256,
+                    scale::Encode::encode(&self.prefix),
+                    Vec::new(),
+                ),
+                layout,
+            ))
+        }
+    }
+};
@@ -0,0 +1,313 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::RawMapping;
+use core::marker::PhantomData;
+use ink::{
+    prelude::vec::Vec,
+    primitives::Key,
+    storage::traits::{
+        AutoKey,
+        Packed,
+        Storable,
+        StorableHint,
+        StorageKey,
+    },
+};
+use scale::{
+    Error,
+    Input,
+    Output,
+};
+
+/// A double-ended queue which stores each element under its own storage key.
+///
+/// Elements are stored in a ring over the whole `u128` index space, so pushing and popping
+/// at both ends is O(1).
+pub struct StorageDeque<V, KeyType: StorageKey = AutoKey> {
+    _marker: PhantomData<fn() -> (V, KeyType)>,
+}
+
+impl<V, KeyType> StorageDeque<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    /// Contains the physical index of the front element and count of elements.
+    /// bounds: (u128, u128),
+    fn bounds(&self) -> RawMapping<(), (u128, u128), (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &0))
+    }
+
+    /// Mapping from physical index to element.
+    /// elements: Mapping<u128, V>,
+    fn elements(&self) -> RawMapping<&u128, V, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &1))
+    }
+}
+
+impl<V, KeyType> StorageDeque<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Returns the count of elements.
+    #[inline]
+    pub fn len(&self) -> u128 {
+        self.bounds().get(()).unwrap_or_default().1
+    }
+
+    /// Checks if the deque contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends the `value` to the back of the deque.
+    pub fn push_back(&mut self, value: &V) {
+        let (head, len) = self.bounds().get(()).unwrap_or_default();
+        self.elements().insert(&head.wrapping_add(len), value);
+        self.bounds().insert((), &(head, len + 1));
+    }
+
+    /// Prepends the `value` to the front of the deque.
+    pub fn push_front(&mut self, value: &V) {
+        let (head, len) = self.bounds().get(()).unwrap_or_default();
+        let head = head.wrapping_sub(1);
+        self.elements().insert(&head, value);
+        self.bounds().insert((), &(head, len + 1));
+    }
+
+    /// Removes the first element and returns it, `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<V> {
+        let (head, len) = self.bounds().get(()).unwrap_or_default();
+        if len == 0 {
+            return None
+        }
+
+        let value = self.elements().get(&head);
+        self.elements().remove(&head);
+        self.bounds().insert((), &(head.wrapping_add(1), len - 1));
+        value
+    }
+
+    /// Removes the last element and returns it, `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<V> {
+        let (head, len) = self.bounds().get(()).unwrap_or_default();
+        if len == 0 {
+            return None
+        }
+
+        let tail = head.wrapping_add(len - 1);
+        let value = self.elements().get(&tail);
+        self.elements().remove(&tail);
+        self.bounds().insert((), &(head, len - 1));
+        value
+    }
+
+    /// Returns the first element, `None` if the deque is empty.
+    #[inline]
+    pub fn front(&self) -> Option<V> {
+        self.get(0)
+    }
+
+    /// Returns the last element, `None` if the deque is empty.
+    #[inline]
+    pub fn back(&self) -> Option<V> {
+        self.get(self.len().checked_sub(1)?)
+    }
+
+    /// Get the element at `index` counted from the front of the deque.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn get(&self, index: u128) -> Option<V> {
+        let (head, len) = self.bounds().get(()).unwrap_or_default();
+        if index >= len {
+            return None
+        }
+        self.elements().get(&head.wrapping_add(index))
+    }
+
+    /// Returns up to `limit` elements starting from the `start` index counted from the front of the deque.
+    pub fn iter_range(&self, start: u128, limit: u128) -> Vec<V> {
+        let (head, len) = self.bounds().get(()).unwrap_or_default();
+        let end = core::cmp::min(start.saturating_add(limit), len);
+
+        (start..end)
+            .filter_map(|index| self.elements().get(&head.wrapping_add(index)))
+            .collect()
+    }
+}
+
+impl<V, KeyType> Default for StorageDeque<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<V, KeyType> core::fmt::Debug for StorageDeque<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("StorageDeque").field("key", &KeyType::KEY).finish()
+    }
+}
+
+impl<V, KeyType> Storable for StorageDeque<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: Input>(_input: &mut I) -> Result<Self, Error> {
+        Ok(Default::default())
+    }
+}
+
+impl<V, Key, InnerKey> StorableHint<Key> for StorageDeque<V, InnerKey>
+where
+    V: Packed,
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = StorageDeque<V, Key>;
+    type PreferredKey = InnerKey;
+}
+
+impl<V, KeyType> StorageKey for StorageDeque<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    const KEY: Key = KeyType::KEY;
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink::{
+        metadata::layout::{
+            FieldLayout,
+            Layout,
+            LayoutKey,
+            LeafLayout,
+            RootLayout,
+            StructLayout,
+        },
+        storage::traits::StorageLayout,
+    };
+    use scale_info::{
+        build::Fields,
+        type_params,
+        Path,
+        Type,
+        TypeInfo,
+    };
+
+    impl<V, KeyType> TypeInfo for StorageDeque<V, KeyType>
+    where
+        V: TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("StorageDeque", module_path!()))
+                .type_params(type_params![V])
+                .composite(Fields::unnamed().field(|f| f.ty::<[V]>()))
+        }
+    }
+
+    impl<V, KeyType> StorageLayout for StorageDeque<V, KeyType>
+    where
+        V: Packed + StorageLayout + scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        fn layout(_: &Key) -> Layout {
+            let key = &KeyType::KEY;
+            let field = |name: &'static str, index: u32, layout: Layout| {
+                FieldLayout::new(name, RawMapping::<(), (), _>::new((key, &index)).layout(key, layout))
+            };
+
+            Layout::Root(RootLayout::new(
+                LayoutKey::from(key),
+                StructLayout::new(
+                    "StorageDeque",
+                    [
+                        field(
+                            "bounds",
+                            0,
+                            LeafLayout::from_key::<(u128, u128)>(LayoutKey::from(key)).into(),
+                        ),
+                        field("elements", 1, <V as StorageLayout>::layout(key)),
+                    ],
+                ),
+            ))
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn push_and_pop_at_both_ends_work() {
+        let mut deque: StorageDeque<u128> = StorageDeque::default();
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+
+        deque.push_back(&2);
+        deque.push_front(&1);
+        deque.push_back(&3);
+
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.front(), Some(1));
+        assert_eq!(deque.back(), Some(3));
+        assert_eq!(deque.iter_range(0, 10), vec![1, 2, 3]);
+
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_back(), Some(2));
+        assert!(deque.is_empty());
+        assert_eq!(deque.front(), None);
+    }
+
+    #[ink::test]
+    fn get_works() {
+        let mut deque: StorageDeque<u128> = StorageDeque::default();
+        deque.push_front(&2);
+        deque.push_front(&1);
+
+        assert_eq!(deque.get(0), Some(1));
+        assert_eq!(deque.get(1), Some(2));
+        assert_eq!(deque.get(2), None);
+        assert_eq!(deque.iter_range(1, 10), vec![2]);
+    }
+}
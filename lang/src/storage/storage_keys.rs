@@ -0,0 +1,250 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use core::marker::PhantomData;
+
+/// Entry of `StorageKeys::STORAGE_KEYS`.
+#[derive(Debug, PartialEq, Eq)]
+pub enum StorageKeyEntry {
+    /// The path of the field which is placed under its own `ManualKey` and the storage key.
+    Key(&'static str, u32),
+    /// The path of the field and the storage keys used by the type of the field,
+    /// for example, by a nested storage item.
+    Nested(&'static str, &'static [StorageKeyEntry]),
+}
+
+/// Storage keys of the fields which are placed under their own `ManualKey` and the storage keys
+/// of the nested storage items, together with the path of the field.
+/// It is implemented by `#[openbrush::storage_item]`.
+pub trait StorageKeys {
+    const STORAGE_KEYS: &'static [StorageKeyEntry];
+}
+
+/// Returns the storage keys of `T` if it implements `StorageKeys`, otherwise empty list.
+///
+/// The inherent associated constant takes precedence over the one from `NoStorageKeys`,
+/// so `StorageKeysProbe::<T>::STORAGE_KEYS` can be used for any storage field.
+#[doc(hidden)]
+pub struct StorageKeysProbe<T>(PhantomData<T>);
+
+impl<T: StorageKeys> StorageKeysProbe<T> {
+    pub const STORAGE_KEYS: &'static [StorageKeyEntry] = T::STORAGE_KEYS;
+}
+
+#[doc(hidden)]
+pub trait NoStorageKeys {
+    const STORAGE_KEYS: &'static [StorageKeyEntry] = &[];
+}
+
+impl<T> NoStorageKeys for StorageKeysProbe<T> {}
+
+const MAX_MESSAGE_LEN: usize = 512;
+
+const fn append(mut buffer: [u8; MAX_MESSAGE_LEN], mut len: usize, str: &str) -> ([u8; MAX_MESSAGE_LEN], usize) {
+    let bytes = str.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() && len < MAX_MESSAGE_LEN {
+        buffer[len] = bytes[i];
+        len += 1;
+        i += 1;
+    }
+    (buffer, len)
+}
+
+const fn collision_panic(first: (&str, &str), second: (&str, &str)) -> ! {
+    let (buffer, len) = append([0; MAX_MESSAGE_LEN], 0, "storage key collision between `");
+    let (buffer, len) = append(buffer, len, first.0);
+    let (buffer, len) = append(buffer, len, "` (`");
+    let (buffer, len) = append(buffer, len, first.1);
+    let (buffer, len) = append(buffer, len, "`) and `");
+    let (buffer, len) = append(buffer, len, second.0);
+    let (buffer, len) = append(buffer, len, "` (`");
+    let (buffer, len) = append(buffer, len, second.1);
+    let (buffer, len) = append(buffer, len, "`)");
+    // `split_at` and range indexing are not usable in `const fn`, so the tail is cut byte by byte
+    let mut message: &[u8] = &buffer;
+    while message.len() > len {
+        if let [init @ .., _] = message {
+            message = init;
+        }
+    }
+
+    match core::str::from_utf8(message) {
+        Ok(message) => panic!("{}", message),
+        // The message can be cut in the middle of a character
+        Err(_) => panic!("storage key collision"),
+    }
+}
+
+// Number of storage keys in the `entries`, including the ones of the nested entries
+const fn keys_count(entries: &[StorageKeyEntry]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < entries.len() {
+        count += match &entries[i] {
+            StorageKeyEntry::Key(_, _) => 1,
+            StorageKeyEntry::Nested(_, nested) => keys_count(nested),
+        };
+        i += 1;
+    }
+    count
+}
+
+// The `n`-th storage key of the `entries` in depth-first order, together with the path of its field
+const fn nth_key(entries: &[StorageKeyEntry], mut n: usize) -> (&'static str, u32) {
+    let mut i = 0;
+    while i < entries.len() {
+        match &entries[i] {
+            StorageKeyEntry::Key(path, key) => {
+                if n == 0 {
+                    return (*path, *key)
+                }
+                n -= 1;
+            }
+            StorageKeyEntry::Nested(_, nested) => {
+                let count = keys_count(nested);
+                if n < count {
+                    return nth_key(nested, n)
+                }
+                n -= count;
+            }
+        }
+        i += 1;
+    }
+    panic!("storage key index out of bounds")
+}
+
+/// Fails the constant evaluation if any storage key is used twice by the `fields`,
+/// the error names both fields.
+///
+/// `#[openbrush::contract]` calls it with the name and the storage keys of each field
+/// of the contract storage: the keys of the storage items (including the nested ones)
+/// and the keys of the fields with an explicit `ManualKey`.
+/// The keys which ink! derives automatically for the fields without `ManualKey` are not checked.
+pub const fn assert_unique_storage_keys(fields: &[(&str, &[StorageKeyEntry])]) {
+    let mut a = 0;
+    while a < fields.len() {
+        let a_count = keys_count(fields[a].1);
+        let mut i = 0;
+        while i < a_count {
+            let first = nth_key(fields[a].1, i);
+            let mut b = a;
+            while b < fields.len() {
+                let b_count = keys_count(fields[b].1);
+                let mut j = if a == b { i + 1 } else { 0 };
+                while j < b_count {
+                    let second = nth_key(fields[b].1, j);
+                    if first.1 == second.1 {
+                        collision_panic((fields[a].0, first.0), (fields[b].0, second.0))
+                    }
+                    j += 1;
+                }
+                b += 1;
+            }
+            i += 1;
+        }
+        a += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct First;
+
+    impl StorageKeys for First {
+        const STORAGE_KEYS: &'static [StorageKeyEntry] = &[
+            StorageKeyEntry::Key("first::Data::a", 1),
+            StorageKeyEntry::Key("first::Data::b", 2),
+        ];
+    }
+
+    struct Second;
+
+    impl StorageKeys for Second {
+        const STORAGE_KEYS: &'static [StorageKeyEntry] = &[StorageKeyEntry::Key("second::Data::c", 3)];
+    }
+
+    struct Outer;
+
+    impl StorageKeys for Outer {
+        const STORAGE_KEYS: &'static [StorageKeyEntry] = &[
+            StorageKeyEntry::Key("outer::Data::d", 4),
+            StorageKeyEntry::Nested("outer::Data::inner", Second::STORAGE_KEYS),
+        ];
+    }
+
+    struct WithoutKeys;
+
+    #[test]
+    fn probe_works() {
+        assert_eq!(StorageKeysProbe::<First>::STORAGE_KEYS, First::STORAGE_KEYS);
+        assert_eq!(StorageKeysProbe::<WithoutKeys>::STORAGE_KEYS, &[]);
+    }
+
+    #[test]
+    fn unique_keys_pass() {
+        assert_unique_storage_keys(&[
+            ("first", StorageKeysProbe::<First>::STORAGE_KEYS),
+            ("empty", StorageKeysProbe::<WithoutKeys>::STORAGE_KEYS),
+            ("second", StorageKeysProbe::<Second>::STORAGE_KEYS),
+        ]);
+        assert_unique_storage_keys(&[
+            ("first", StorageKeysProbe::<First>::STORAGE_KEYS),
+            ("outer", StorageKeysProbe::<Outer>::STORAGE_KEYS),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "storage key collision between `first` (`first::Data::b`) and `second` (`second::Data::d`)"
+    )]
+    fn collision_between_fields_fails() {
+        assert_unique_storage_keys(&[
+            ("first", First::STORAGE_KEYS),
+            ("second", &[StorageKeyEntry::Key("second::Data::d", 2)]),
+        ]);
+    }
+
+    #[test]
+    #[should_panic(expected = "storage key collision between `first` (`first::Data::a`) and `copy` (`first::Data::a`)")]
+    fn same_storage_item_twice_fails() {
+        assert_unique_storage_keys(&[("first", First::STORAGE_KEYS), ("copy", First::STORAGE_KEYS)]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "storage key collision between `second` (`second::Data::c`) and `outer` (`second::Data::c`)"
+    )]
+    fn collision_with_nested_storage_item_fails() {
+        assert_unique_storage_keys(&[("second", Second::STORAGE_KEYS), ("outer", Outer::STORAGE_KEYS)]);
+    }
+
+    #[test]
+    #[should_panic(expected = "storage key collision between `outer` (`outer::Data::d`) and `manual` (`manual`)")]
+    fn collision_with_manual_key_fails() {
+        assert_unique_storage_keys(&[
+            ("outer", Outer::STORAGE_KEYS),
+            ("manual", &[StorageKeyEntry::Key("manual", 4)]),
+        ]);
+    }
+}
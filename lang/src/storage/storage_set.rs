@@ -0,0 +1,313 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::RawMapping;
+use core::marker::PhantomData;
+use ink::{
+    prelude::vec::Vec,
+    primitives::Key,
+    storage::traits::{
+        AutoKey,
+        Packed,
+        Storable,
+        StorableHint,
+        StorageKey,
+    },
+};
+use scale::{
+    Error,
+    Input,
+    Output,
+};
+
+/// A set which stores each element under its own storage key and keeps an index of elements
+/// for the iteration.
+///
+/// Insertion and removal are O(1), the removed element is replaced with the last element in the index,
+/// so the order of elements is not preserved after removal.
+pub struct StorageSet<V, KeyType: StorageKey = AutoKey> {
+    _marker: PhantomData<fn() -> (V, KeyType)>,
+}
+
+impl<V, KeyType> StorageSet<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    /// Contains count of elements.
+    /// len: u128,
+    fn len_storage(&self) -> RawMapping<(), u128, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &0))
+    }
+
+    /// Mapping from element to its index.
+    /// value_to_index: Mapping<V, u128>,
+    fn value_to_index(&self) -> RawMapping<&V, u128, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &1))
+    }
+
+    /// Mapping from index to element.
+    /// index_to_value: Mapping<u128, V>,
+    fn index_to_value(&self) -> RawMapping<&u128, V, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &2))
+    }
+}
+
+impl<V, KeyType> StorageSet<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Returns the count of elements.
+    #[inline]
+    pub fn len(&self) -> u128 {
+        self.len_storage().get(()).unwrap_or_default()
+    }
+
+    /// Checks if the set contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Adds the `value` to the set.
+    ///
+    /// Returns whether the `value` was newly inserted.
+    pub fn insert(&mut self, value: &V) -> bool {
+        if self.contains(value) {
+            return false
+        }
+
+        let index = self.len();
+        self.value_to_index().insert(value, &index);
+        self.index_to_value().insert(&index, value);
+        self.len_storage().insert((), &(index + 1));
+        true
+    }
+
+    /// Checks if the `value` is stored in the set.
+    #[inline]
+    pub fn contains(&self, value: &V) -> bool {
+        self.value_to_index().contains(value)
+    }
+
+    /// Get the index of the `value` in the set.
+    ///
+    /// Returns `None` if the `value` is not stored in the set.
+    #[inline]
+    pub fn get_index(&self, value: &V) -> Option<u128> {
+        self.value_to_index().get(value)
+    }
+
+    /// Get the element at `index` from the contract storage.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: u128) -> Option<V> {
+        self.index_to_value().get(&index)
+    }
+
+    /// Removes the `value` from the set, the last element is moved to its index.
+    ///
+    /// Returns whether the `value` was present in the set.
+    pub fn remove(&mut self, value: &V) -> bool {
+        let index = match self.get_index(value) {
+            Some(index) => index,
+            None => return false,
+        };
+        let last_index = self.len() - 1;
+
+        if index != last_index {
+            let last_value = self
+                .index_to_value()
+                .get(&last_index)
+                .expect("The value under the last index should exist");
+            self.index_to_value().insert(&index, &last_value);
+            self.value_to_index().insert(&last_value, &index);
+        }
+
+        self.index_to_value().remove(&last_index);
+        self.value_to_index().remove(value);
+        self.len_storage().insert((), &last_index);
+        true
+    }
+
+    /// Returns up to `limit` elements starting from the `start` index.
+    pub fn iter_range(&self, start: u128, limit: u128) -> Vec<V> {
+        let end = core::cmp::min(start.saturating_add(limit), self.len());
+
+        (start..end)
+            .filter_map(|index| self.index_to_value().get(&index))
+            .collect()
+    }
+}
+
+impl<V, KeyType> Default for StorageSet<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<V, KeyType> core::fmt::Debug for StorageSet<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("StorageSet").field("key", &KeyType::KEY).finish()
+    }
+}
+
+impl<V, KeyType> Storable for StorageSet<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: Input>(_input: &mut I) -> Result<Self, Error> {
+        Ok(Default::default())
+    }
+}
+
+impl<V, Key, InnerKey> StorableHint<Key> for StorageSet<V, InnerKey>
+where
+    V: Packed,
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = StorageSet<V, Key>;
+    type PreferredKey = InnerKey;
+}
+
+impl<V, KeyType> StorageKey for StorageSet<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    const KEY: Key = KeyType::KEY;
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink::{
+        metadata::layout::{
+            FieldLayout,
+            Layout,
+            LayoutKey,
+            LeafLayout,
+            RootLayout,
+            StructLayout,
+        },
+        storage::traits::StorageLayout,
+    };
+    use scale_info::{
+        build::Fields,
+        type_params,
+        Path,
+        Type,
+        TypeInfo,
+    };
+
+    impl<V, KeyType> TypeInfo for StorageSet<V, KeyType>
+    where
+        V: TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("StorageSet", module_path!()))
+                .type_params(type_params![V])
+                .composite(Fields::unnamed().field(|f| f.ty::<[V]>()))
+        }
+    }
+
+    impl<V, KeyType> StorageLayout for StorageSet<V, KeyType>
+    where
+        V: Packed + StorageLayout + scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        fn layout(_: &Key) -> Layout {
+            let key = &KeyType::KEY;
+            let field = |name: &'static str, index: u32, layout: Layout| {
+                FieldLayout::new(name, RawMapping::<(), (), _>::new((key, &index)).layout(key, layout))
+            };
+
+            Layout::Root(RootLayout::new(
+                LayoutKey::from(key),
+                StructLayout::new(
+                    "StorageSet",
+                    [
+                        field("len", 0, LeafLayout::from_key::<u128>(LayoutKey::from(key)).into()),
+                        field(
+                            "value_to_index",
+                            1,
+                            LeafLayout::from_key::<u128>(LayoutKey::from(key)).into(),
+                        ),
+                        field("index_to_value", 2, <V as StorageLayout>::layout(key)),
+                    ],
+                ),
+            ))
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn insert_and_contains_work() {
+        let mut set: StorageSet<u128> = StorageSet::default();
+
+        assert!(set.insert(&1));
+        assert!(set.insert(&2));
+        assert!(!set.insert(&1));
+
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&1));
+        assert!(!set.contains(&3));
+        assert_eq!(set.get_index(&2), Some(1));
+        assert_eq!(set.get(1), Some(2));
+    }
+
+    #[ink::test]
+    fn remove_swaps_last_element() {
+        let mut set: StorageSet<u128> = StorageSet::default();
+        set.insert(&1);
+        set.insert(&2);
+        set.insert(&3);
+
+        assert!(set.remove(&1));
+        assert!(!set.remove(&1));
+
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get_index(&3), Some(0));
+        assert_eq!(set.iter_range(0, 10), vec![3, 2]);
+    }
+}
@@ -0,0 +1,360 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+use super::RawMapping;
+use core::marker::PhantomData;
+use ink::{
+    prelude::vec::Vec,
+    primitives::Key,
+    storage::traits::{
+        AutoKey,
+        Packed,
+        Storable,
+        StorableHint,
+        StorageKey,
+    },
+};
+use scale::{
+    Error,
+    Input,
+    Output,
+};
+
+/// A vector which stores each element under its own storage key instead of encoding the whole
+/// `Vec` into one storage cell, so only the accessed elements are loaded from the storage.
+pub struct StorageVec<V, KeyType: StorageKey = AutoKey> {
+    _marker: PhantomData<fn() -> (V, KeyType)>,
+}
+
+impl<V, KeyType> StorageVec<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    /// Contains count of elements.
+    /// len: u128,
+    fn len_storage(&self) -> RawMapping<(), u128, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &0))
+    }
+
+    /// Mapping from index to element.
+    /// elements: Mapping<u128, V>,
+    fn elements(&self) -> RawMapping<&u128, V, (&Key, &u32)> {
+        RawMapping::new((&KeyType::KEY, &1))
+    }
+}
+
+impl<V, KeyType> StorageVec<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    /// Returns the count of elements.
+    #[inline]
+    pub fn len(&self) -> u128 {
+        self.len_storage().get(()).unwrap_or_default()
+    }
+
+    /// Checks if the vector contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends the `value` to the back of the vector.
+    pub fn push(&mut self, value: &V) {
+        let len = self.len();
+        self.elements().insert(&len, value);
+        self.len_storage().insert((), &(len + 1));
+    }
+
+    /// Removes the last element from the vector and returns it, `None` if it is empty.
+    pub fn pop(&mut self) -> Option<V> {
+        let last_index = self.len().checked_sub(1)?;
+        let value = self.elements().get(&last_index);
+        self.elements().remove(&last_index);
+        self.len_storage().insert((), &last_index);
+        value
+    }
+
+    /// Get the element at `index` from the contract storage.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[inline]
+    pub fn get(&self, index: u128) -> Option<V> {
+        if index >= self.len() {
+            return None
+        }
+        self.elements().get(&index)
+    }
+
+    /// Replaces the element at `index` with the `value`.
+    ///
+    /// Returns `false` and doesn't store the `value` if `index` is out of bounds.
+    pub fn set(&mut self, index: u128, value: &V) -> bool {
+        if index >= self.len() {
+            return false
+        }
+        self.elements().insert(&index, value);
+        true
+    }
+
+    /// Removes the element at `index` and returns it, the last element is moved to its place.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn swap_remove(&mut self, index: u128) -> Option<V> {
+        let last_index = self.len().checked_sub(1)?;
+        if index > last_index {
+            return None
+        }
+
+        let value = self.elements().get(&index);
+        if index != last_index {
+            let last_value = self
+                .elements()
+                .get(&last_index)
+                .expect("The element under the last index should exist");
+            self.elements().insert(&index, &last_value);
+        }
+        self.elements().remove(&last_index);
+        self.len_storage().insert((), &last_index);
+        value
+    }
+
+    /// Returns up to `limit` elements starting from the `start` index.
+    pub fn iter_range(&self, start: u128, limit: u128) -> Vec<V> {
+        let end = core::cmp::min(start.saturating_add(limit), self.len());
+
+        (start..end).filter_map(|index| self.elements().get(&index)).collect()
+    }
+
+    /// Removes all elements from the vector.
+    ///
+    /// Each element is removed from the storage separately, so the cost grows with the length.
+    pub fn clear(&mut self) {
+        for index in 0..self.len() {
+            self.elements().remove(&index);
+        }
+        self.len_storage().insert((), &0);
+    }
+}
+
+impl<V, KeyType> Default for StorageVec<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn default() -> Self {
+        Self {
+            _marker: Default::default(),
+        }
+    }
+}
+
+impl<V, KeyType> core::fmt::Debug for StorageVec<V, KeyType>
+where
+    KeyType: StorageKey,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("StorageVec").field("key", &KeyType::KEY).finish()
+    }
+}
+
+impl<V, KeyType> Storable for StorageVec<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    #[inline]
+    fn encode<T: Output + ?Sized>(&self, _dest: &mut T) {}
+
+    #[inline]
+    fn decode<I: Input>(_input: &mut I) -> Result<Self, Error> {
+        Ok(Default::default())
+    }
+}
+
+impl<V, Key, InnerKey> StorableHint<Key> for StorageVec<V, InnerKey>
+where
+    V: Packed,
+    Key: StorageKey,
+    InnerKey: StorageKey,
+{
+    type Type = StorageVec<V, Key>;
+    type PreferredKey = InnerKey;
+}
+
+impl<V, KeyType> StorageKey for StorageVec<V, KeyType>
+where
+    V: Packed,
+    KeyType: StorageKey,
+{
+    const KEY: Key = KeyType::KEY;
+}
+
+#[cfg(feature = "std")]
+const _: () = {
+    use ink::{
+        metadata::layout::{
+            FieldLayout,
+            Layout,
+            LayoutKey,
+            LeafLayout,
+            RootLayout,
+            StructLayout,
+        },
+        storage::traits::StorageLayout,
+    };
+    use scale_info::{
+        build::Fields,
+        type_params,
+        Path,
+        Type,
+        TypeInfo,
+    };
+
+    impl<V, KeyType> TypeInfo for StorageVec<V, KeyType>
+    where
+        V: TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        type Identity = Self;
+
+        fn type_info() -> Type {
+            Type::builder()
+                .path(Path::new("StorageVec", module_path!()))
+                .type_params(type_params![V])
+                .composite(Fields::unnamed().field(|f| f.ty::<[V]>()))
+        }
+    }
+
+    impl<V, KeyType> StorageLayout for StorageVec<V, KeyType>
+    where
+        V: Packed + StorageLayout + scale_info::TypeInfo + 'static,
+        KeyType: StorageKey + 'static,
+    {
+        fn layout(_: &Key) -> Layout {
+            let key = &KeyType::KEY;
+            let field = |name: &'static str, index: u32, layout: Layout| {
+                FieldLayout::new(name, RawMapping::<(), (), _>::new((key, &index)).layout(key, layout))
+            };
+
+            Layout::Root(RootLayout::new(
+                LayoutKey::from(key),
+                StructLayout::new(
+                    "StorageVec",
+                    [
+                        field("len", 0, LeafLayout::from_key::<u128>(LayoutKey::from(key)).into()),
+                        field("elements", 1, <V as StorageLayout>::layout(key)),
+                    ],
+                ),
+            ))
+        }
+    }
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[ink::test]
+    fn push_pop_and_get_work() {
+        let mut vec: StorageVec<u128> = StorageVec::default();
+        assert!(vec.is_empty());
+        assert_eq!(vec.pop(), None);
+
+        vec.push(&1);
+        vec.push(&2);
+        vec.push(&3);
+
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.get(0), Some(1));
+        assert_eq!(vec.get(3), None);
+        assert_eq!(vec.pop(), Some(3));
+        assert_eq!(vec.get(2), None);
+        assert_eq!(vec.len(), 2);
+    }
+
+    #[ink::test]
+    fn set_and_swap_remove_work() {
+        let mut vec: StorageVec<u128> = StorageVec::default();
+        vec.push(&1);
+        vec.push(&2);
+        vec.push(&3);
+
+        assert!(vec.set(1, &20));
+        assert!(!vec.set(3, &40));
+        assert_eq!(vec.swap_remove(0), Some(1));
+        assert_eq!(vec.swap_remove(5), None);
+        assert_eq!(vec.iter_range(0, 10), vec![3, 20]);
+
+        vec.clear();
+        assert!(vec.is_empty());
+        assert_eq!(vec.iter_range(0, 10), vec![]);
+    }
+
+    #[ink::test]
+    fn layout_reports_prefixes_of_stored_elements() {
+        use ink::{
+            metadata::layout::Layout,
+            storage::traits::{
+                ManualKey,
+                StorageLayout,
+            },
+        };
+
+        let mut vec: StorageVec<u64, ManualKey<123>> = StorageVec::default();
+        vec.push(&10);
+
+        let layout = <StorageVec<u64, ManualKey<123>> as StorageLayout>::layout(&123);
+        let prefixes = match &layout {
+            Layout::Root(root) => {
+                match root.layout() {
+                    Layout::Struct(structure) => {
+                        structure
+                            .fields()
+                            .iter()
+                            .map(|field| {
+                                match field.layout() {
+                                    Layout::Hash(hash) => (*field.name(), hash.strategy().prefix().to_vec()),
+                                    _ => panic!("The field should be a hash layout"),
+                                }
+                            })
+                            .collect::<Vec<_>>()
+                    }
+                    _ => panic!("The root should contain a struct"),
+                }
+            }
+            _ => panic!("The layout should be a root"),
+        };
+
+        assert_eq!(
+            prefixes,
+            vec![
+                ("len", scale::Encode::encode(&(123u32, 0u32))),
+                ("elements", scale::Encode::encode(&(123u32, 1u32))),
+            ]
+        );
+        // The elements are stored under the prefix followed by the index
+        assert_eq!(
+            ink::env::get_contract_storage::<_, u64>(&((&123u32, &1u32), &0u128)),
+            Ok(Some(10))
+        );
+    }
+}
@@ -381,4 +381,94 @@ mod access_control {
             Err(AccessControlError::MissingRole)
         );
     }
+
+    fn advance_time(delta: Timestamp) {
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(now + delta);
+    }
+
+    #[ink::test]
+    fn should_grant_role_with_expiry() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        let expiry = ink::env::block_timestamp::<DefaultEnvironment>() + 100;
+
+        assert!(AccessControl::grant_role_with_expiry(&mut access_control, PAUSER, Some(accounts.bob), expiry).is_ok());
+        assert!(AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+        assert_eq!(
+            AccessControl::role_expiry(&access_control, PAUSER, Some(accounts.bob)),
+            Some(expiry)
+        );
+
+        advance_time(100);
+        assert!(!AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_role_granted_event(&emitted_events[1], PAUSER, Some(accounts.bob), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn should_grant_role_with_expiry_fail() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        advance_time(100);
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+
+        assert_eq!(
+            AccessControl::grant_role_with_expiry(&mut access_control, PAUSER, Some(accounts.bob), now),
+            Err(AccessControlError::RoleExpired)
+        );
+        assert!(AccessControl::grant_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+        assert_eq!(
+            AccessControl::grant_role_with_expiry(&mut access_control, PAUSER, Some(accounts.bob), now + 100),
+            Err(AccessControlError::RoleRedundant)
+        );
+        assert_eq!(
+            AccessControl::role_expiry(&access_control, PAUSER, Some(accounts.bob)),
+            None
+        );
+    }
+
+    #[ink::test]
+    fn should_return_error_when_role_expired() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        let expiry = ink::env::block_timestamp::<DefaultEnvironment>() + 100;
+
+        assert!(AccessControl::grant_role_with_expiry(&mut access_control, MINTER, Some(accounts.eve), expiry).is_ok());
+        access_control::Internal::_set_role_admin(&mut access_control, PAUSER, MINTER);
+        advance_time(100);
+
+        change_caller(accounts.eve);
+        assert_eq!(
+            AccessControl::grant_role(&mut access_control, PAUSER, Some(accounts.bob)),
+            Err(AccessControlError::RoleExpired)
+        );
+    }
+
+    #[ink::test]
+    fn should_revoke_and_grant_expired_role() {
+        let accounts = setup();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+        let expiry = ink::env::block_timestamp::<DefaultEnvironment>() + 100;
+
+        assert!(AccessControl::grant_role_with_expiry(&mut access_control, PAUSER, Some(accounts.bob), expiry).is_ok());
+        advance_time(100);
+
+        assert!(AccessControl::grant_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+        assert!(AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+        assert_eq!(
+            AccessControl::role_expiry(&access_control, PAUSER, Some(accounts.bob)),
+            None
+        );
+
+        let expiry = ink::env::block_timestamp::<DefaultEnvironment>() + 100;
+        assert!(AccessControl::grant_role_with_expiry(&mut access_control, MINTER, Some(accounts.bob), expiry).is_ok());
+        advance_time(100);
+        assert!(AccessControl::revoke_role(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert_eq!(
+            AccessControl::role_expiry(&access_control, MINTER, Some(accounts.bob)),
+            None
+        );
+    }
 }
@@ -0,0 +1,224 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "access_control")]
+#[openbrush::implementation(AccessControl, AccessControlDefaultAdminRules)]
+#[openbrush::contract]
+mod access_control_default_admin_rules {
+    use ::ink::env::DefaultEnvironment;
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const MINTER: RoleType = ink::selector_id!("MINTER");
+    const DELAY: Timestamp = 1000;
+
+    #[derive(Default, Storage)]
+    #[ink(storage)]
+    pub struct AccessControlStruct {
+        #[storage_field]
+        access: access_control::Data,
+        #[storage_field]
+        rules: default_admin_rules::Data,
+    }
+
+    impl AccessControlStruct {
+        #[ink(constructor)]
+        pub fn new(admin: AccountId) -> Self {
+            let mut instance = Self::default();
+
+            default_admin_rules::Internal::_init_with_default_admin(&mut instance, Some(admin), DELAY);
+
+            instance
+        }
+    }
+
+    fn advance_time(delta: Timestamp) {
+        let now = ink::env::block_timestamp::<DefaultEnvironment>();
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(now + delta);
+    }
+
+    #[ink::test]
+    fn should_init_with_default_admin() {
+        let accounts = accounts();
+        let access_control = AccessControlStruct::new(accounts.alice);
+
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin(&access_control),
+            Some(accounts.alice)
+        );
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin_delay(&access_control),
+            DELAY
+        );
+        assert!(AccessControl::has_role(
+            &access_control,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+    }
+
+    #[ink::test]
+    fn should_not_grant_or_revoke_default_admin_role() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert_eq!(
+            AccessControl::grant_role(&mut access_control, DEFAULT_ADMIN_ROLE, Some(accounts.bob)),
+            Err(AccessControlError::EnforcedDefaultAdminRules)
+        );
+        assert_eq!(
+            AccessControl::revoke_role(&mut access_control, DEFAULT_ADMIN_ROLE, Some(accounts.alice)),
+            Err(AccessControlError::EnforcedDefaultAdminRules)
+        );
+        assert!(AccessControl::grant_role(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+        assert!(AccessControl::revoke_role(&mut access_control, MINTER, Some(accounts.bob)).is_ok());
+    }
+
+    #[ink::test]
+    fn should_transfer_default_admin_after_delay() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut access_control, Some(accounts.bob))
+                .is_ok()
+        );
+        let (pending_admin, schedule) = AccessControlDefaultAdminRules::pending_default_admin(&access_control);
+        assert_eq!(pending_admin, Some(accounts.bob));
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut access_control),
+            Err(AccessControlError::DefaultAdminTransferNotReady)
+        );
+
+        advance_time(DELAY);
+        assert!(ink::env::block_timestamp::<DefaultEnvironment>() >= schedule);
+        assert!(AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut access_control).is_ok());
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin(&access_control),
+            Some(accounts.bob)
+        );
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin(&access_control),
+            (None, 0)
+        );
+        assert!(AccessControl::has_role(
+            &access_control,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.bob)
+        ));
+        assert!(!AccessControl::has_role(
+            &access_control,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+    }
+
+    #[ink::test]
+    fn should_not_accept_if_not_pending_admin() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut access_control, Some(accounts.bob))
+                .is_ok()
+        );
+        advance_time(DELAY);
+
+        change_caller(accounts.charlie);
+        assert_eq!(
+            AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut access_control),
+            Err(AccessControlError::InvalidCaller)
+        );
+        assert_eq!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut access_control, Some(accounts.charlie)),
+            Err(AccessControlError::MissingRole)
+        );
+    }
+
+    #[ink::test]
+    fn should_cancel_default_admin_transfer() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(
+            AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut access_control, Some(accounts.bob))
+                .is_ok()
+        );
+        assert!(AccessControlDefaultAdminRules::cancel_default_admin_transfer(&mut access_control).is_ok());
+        assert_eq!(
+            AccessControlDefaultAdminRules::pending_default_admin(&access_control),
+            (None, 0)
+        );
+
+        advance_time(DELAY);
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControlDefaultAdminRules::accept_default_admin_transfer(&mut access_control),
+            Err(AccessControlError::InvalidCaller)
+        );
+    }
+
+    #[ink::test]
+    fn should_renounce_default_admin_only_after_scheduled() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert_eq!(
+            AccessControl::renounce_role(&mut access_control, DEFAULT_ADMIN_ROLE, Some(accounts.alice)),
+            Err(AccessControlError::DefaultAdminTransferNotReady)
+        );
+
+        assert!(AccessControlDefaultAdminRules::begin_default_admin_transfer(&mut access_control, None).is_ok());
+        advance_time(DELAY);
+        assert!(AccessControl::renounce_role(&mut access_control, DEFAULT_ADMIN_ROLE, Some(accounts.alice)).is_ok());
+        assert_eq!(AccessControlDefaultAdminRules::default_admin(&access_control), None);
+        assert!(!AccessControl::has_role(
+            &access_control,
+            DEFAULT_ADMIN_ROLE,
+            Some(accounts.alice)
+        ));
+    }
+
+    #[ink::test]
+    fn should_change_default_admin_delay() {
+        let accounts = accounts();
+        let mut access_control = AccessControlStruct::new(accounts.alice);
+
+        assert!(AccessControlDefaultAdminRules::change_default_admin_delay(&mut access_control, 5000).is_ok());
+        assert_eq!(
+            AccessControlDefaultAdminRules::default_admin_delay(&access_control),
+            5000
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            AccessControlDefaultAdminRules::change_default_admin_delay(&mut access_control, 0),
+            Err(AccessControlError::MissingRole)
+        );
+    }
+}
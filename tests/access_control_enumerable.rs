@@ -133,4 +133,32 @@ mod access_control_enumerable {
             None
         )
     }
+
+    #[ink::test]
+    fn should_not_have_expired_role() {
+        let accounts = setup();
+        let alice = accounts.alice;
+        let mut access_control = AccessControlStruct::new(alice);
+        let expiry = ink::env::block_timestamp::<DefaultEnvironment>() + 100;
+
+        assert!(AccessControl::grant_role_with_expiry(&mut access_control, PAUSER, Some(accounts.bob), expiry).is_ok());
+        assert!(AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+        assert_eq!(
+            AccessControl::role_expiry(&access_control, PAUSER, Some(accounts.bob)),
+            Some(expiry)
+        );
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(expiry);
+        assert!(!AccessControl::has_role(&access_control, PAUSER, Some(accounts.bob)));
+
+        assert!(AccessControl::revoke_role(&mut access_control, PAUSER, Some(accounts.bob)).is_ok());
+        assert_eq!(
+            AccessControlEnumerable::get_role_member_count(&mut access_control, PAUSER),
+            0
+        );
+        assert_eq!(
+            AccessControl::role_expiry(&access_control, PAUSER, Some(accounts.bob)),
+            None
+        );
+    }
 }
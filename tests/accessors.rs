@@ -0,0 +1,111 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "ownable")]
+use openbrush::{
+    contracts::ownable::*,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+#[cfg(feature = "ownable")]
+#[openbrush::accessors(ConfigAccessors: Storage<ownable::Data>, modifiers(ownable::only_owner), error = OwnableError)]
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct ConfigData {
+    #[get]
+    #[set]
+    fee: u32,
+    #[get]
+    #[set]
+    #[lazy]
+    treasury: Option<AccountId>,
+    #[get]
+    #[set]
+    limits: Mapping<AccountId, u128>,
+}
+
+#[cfg(feature = "ownable")]
+#[openbrush::implementation(Ownable)]
+#[openbrush::contract]
+mod accessors {
+    use crate::*;
+    use openbrush::test_utils::{
+        accounts,
+        change_caller,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        config: ConfigData,
+    }
+
+    impl ConfigAccessors for Contract {}
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn owner_can_set_config() {
+        let accounts = accounts();
+        let mut contract = Contract::new();
+
+        assert_eq!(ConfigAccessors::set_fee(&mut contract, 10), Ok(()));
+        assert_eq!(ConfigAccessors::set_treasury(&mut contract, Some(accounts.bob)), Ok(()));
+        assert_eq!(ConfigAccessors::set_limits(&mut contract, accounts.bob, 100), Ok(()));
+
+        assert_eq!(ConfigAccessors::get_fee(&contract), 10);
+        assert_eq!(ConfigAccessors::get_treasury(&contract), Some(accounts.bob));
+        assert_eq!(ConfigAccessors::get_limits(&contract, accounts.bob), Some(100));
+        assert_eq!(ConfigAccessors::get_limits(&contract, accounts.alice), None);
+    }
+
+    #[ink::test]
+    fn not_owner_cannot_set_config() {
+        let accounts = accounts();
+        let mut contract = Contract::new();
+        change_caller(accounts.bob);
+
+        assert_eq!(
+            ConfigAccessors::set_fee(&mut contract, 10),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(
+            ConfigAccessors::set_limits(&mut contract, accounts.bob, 100),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(ConfigAccessors::get_fee(&contract), 0);
+        assert_eq!(ConfigAccessors::get_limits(&contract, accounts.bob), None);
+    }
+}
@@ -0,0 +1,378 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "governor")]
+#[openbrush::implementation(Governor)]
+#[openbrush::contract]
+mod governor {
+    use ink::{
+        env::{
+            test::advance_block,
+            DefaultEnvironment,
+        },
+        storage::Mapping,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    const VOTING_DELAY: BlockNumber = 1;
+    const VOTING_PERIOD: BlockNumber = 5;
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct GovernorStruct {
+        #[storage_field]
+        governor: governor::Data,
+        votes: Mapping<AccountId, Balance>,
+        total_supply: Balance,
+        scheduled: Vec<Transaction>,
+        executed: Vec<Transaction>,
+    }
+
+    /// We will override governor functions so they are not using cross-contract calls in tests
+    /// The votes are taken from the mocked balances and the timelock just stores the transactions
+    /// The cross-contract interaction will be tested in integration tests
+    #[overrider(governor::Internal)]
+    fn _get_votes(&self, account: &AccountId, _block_number: BlockNumber) -> Result<Balance, GovernorError> {
+        Ok(self.votes.get(account).unwrap_or_default())
+    }
+
+    #[overrider(governor::Internal)]
+    fn _get_past_total_supply(&self, _block_number: BlockNumber) -> Result<Balance, GovernorError> {
+        Ok(self.total_supply)
+    }
+
+    #[overrider(governor::Internal)]
+    fn _schedule_operation(&mut self, transactions: Vec<Transaction>, _salt: [u8; 32]) -> Result<(), GovernorError> {
+        self.scheduled = transactions;
+        Ok(())
+    }
+
+    #[overrider(governor::Internal)]
+    fn _execute_operation(&mut self, transactions: Vec<Transaction>, _salt: [u8; 32]) -> Result<(), GovernorError> {
+        self.scheduled = Vec::new();
+        self.executed = transactions;
+        Ok(())
+    }
+
+    #[overrider(governor::Internal)]
+    fn _cancel_operation(&mut self, _transactions: Vec<Transaction>, _salt: [u8; 32]) -> Result<(), GovernorError> {
+        self.scheduled = Vec::new();
+        Ok(())
+    }
+
+    impl GovernorStruct {
+        #[ink(constructor)]
+        pub fn new(timelock: AccountId, proposal_threshold: Balance, quorum_numerator: u128) -> Self {
+            let mut instance = Self::default();
+            let accounts = accounts();
+            instance.votes.insert(accounts.alice, &60);
+            instance.votes.insert(accounts.bob, &30);
+            instance.votes.insert(accounts.charlie, &10);
+            instance.total_supply = 100;
+            assert!(governor::Internal::_init(
+                &mut instance,
+                AccountId::from([0x1; 32]),
+                timelock,
+                VOTING_DELAY,
+                VOTING_PERIOD,
+                proposal_threshold,
+                quorum_numerator,
+            )
+            .is_ok());
+            instance
+        }
+    }
+
+    fn transactions() -> Vec<Transaction> {
+        vec![Transaction {
+            callee: Some(AccountId::from([0x2; 32])),
+            selector: [0, 0, 0, 0],
+            input: vec![],
+            transferred_value: 0,
+            gas_limit: 0,
+        }]
+    }
+
+    fn description_hash(instance: &GovernorStruct) -> [u8; 32] {
+        governor::Internal::_hash_description(instance, "Proposal #1")
+    }
+
+    fn propose(instance: &mut GovernorStruct) -> ProposalId {
+        Governor::propose(instance, transactions(), String::from("Proposal #1")).unwrap()
+    }
+
+    fn advance_blocks(blocks: BlockNumber) {
+        for _ in 0..blocks {
+            advance_block::<DefaultEnvironment>();
+        }
+    }
+
+    #[ink::test]
+    fn new_works() {
+        let timelock = accounts().django;
+        let instance = GovernorStruct::new(timelock, 10, 20);
+
+        assert_eq!(Governor::token(&instance), Some(AccountId::from([0x1; 32])));
+        assert_eq!(Governor::timelock(&instance), Some(timelock));
+        assert_eq!(Governor::voting_delay(&instance), VOTING_DELAY);
+        assert_eq!(Governor::voting_period(&instance), VOTING_PERIOD);
+        assert_eq!(Governor::proposal_threshold(&instance), 10);
+        assert_eq!(Governor::quorum_numerator(&instance), 20);
+        assert_eq!(Governor::quorum(&instance, 0), Ok(20));
+    }
+
+    #[ink::test]
+    fn propose_works() {
+        advance_block::<DefaultEnvironment>();
+        let mut instance = GovernorStruct::new(accounts().django, 10, 20);
+
+        let proposal_id = propose(&mut instance);
+
+        assert_eq!(
+            Governor::hash_proposal(&instance, transactions(), description_hash(&instance)),
+            proposal_id
+        );
+        assert_eq!(Governor::state(&instance, proposal_id), Ok(ProposalState::Pending));
+        assert_eq!(
+            Governor::proposal_proposer(&instance, proposal_id),
+            Ok(accounts().alice)
+        );
+        assert_eq!(
+            Governor::proposal_snapshot(&instance, proposal_id),
+            Ok(1 + VOTING_DELAY)
+        );
+        assert_eq!(
+            Governor::proposal_deadline(&instance, proposal_id),
+            Ok(1 + VOTING_DELAY + VOTING_PERIOD)
+        );
+        assert_eq!(
+            Governor::propose(&mut instance, transactions(), String::from("Proposal #1")),
+            Err(GovernorError::ProposalAlreadyExists)
+        );
+        assert_eq!(
+            Governor::propose(&mut instance, vec![], String::from("Proposal #2")),
+            Err(GovernorError::EmptyProposal)
+        );
+    }
+
+    #[ink::test]
+    fn propose_fails_below_threshold() {
+        advance_block::<DefaultEnvironment>();
+        let mut instance = GovernorStruct::new(accounts().django, 20, 20);
+
+        change_caller(accounts().charlie);
+        assert_eq!(
+            Governor::propose(&mut instance, transactions(), String::from("Proposal #1")),
+            Err(GovernorError::InsufficientProposerVotes)
+        );
+    }
+
+    #[ink::test]
+    fn propose_fails_with_threshold_at_first_block() {
+        let mut instance = GovernorStruct::new(accounts().django, 10, 20);
+
+        assert_eq!(
+            Governor::propose(&mut instance, transactions(), String::from("Proposal #1")),
+            Err(GovernorError::InsufficientProposerVotes)
+        );
+    }
+
+    #[ink::test]
+    fn cast_vote_works() {
+        let accounts = accounts();
+        let mut instance = GovernorStruct::new(accounts.django, 0, 20);
+        let proposal_id = propose(&mut instance);
+
+        assert_eq!(
+            Governor::cast_vote(&mut instance, proposal_id, VoteType::For),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+
+        advance_blocks(VOTING_DELAY + 1);
+        assert_eq!(Governor::state(&instance, proposal_id), Ok(ProposalState::Active));
+
+        assert_eq!(Governor::cast_vote(&mut instance, proposal_id, VoteType::For), Ok(60));
+        change_caller(accounts.bob);
+        assert_eq!(
+            Governor::cast_vote(&mut instance, proposal_id, VoteType::Against),
+            Ok(30)
+        );
+        change_caller(accounts.charlie);
+        assert_eq!(
+            Governor::cast_vote(&mut instance, proposal_id, VoteType::Abstain),
+            Ok(10)
+        );
+        assert_eq!(
+            Governor::cast_vote(&mut instance, proposal_id, VoteType::For),
+            Err(GovernorError::AlreadyCastVote)
+        );
+
+        assert!(Governor::has_voted(&instance, proposal_id, accounts.alice));
+        assert!(!Governor::has_voted(&instance, proposal_id, accounts.django));
+        assert_eq!(
+            Governor::proposal_votes(&instance, proposal_id),
+            ProposalVote {
+                against_votes: 30,
+                for_votes: 60,
+                abstain_votes: 10,
+            }
+        );
+    }
+
+    #[ink::test]
+    fn succeeded_proposal_is_queued_and_executed() {
+        let mut instance = GovernorStruct::new(accounts().django, 0, 20);
+        let proposal_id = propose(&mut instance);
+        let description_hash = description_hash(&instance);
+
+        advance_blocks(VOTING_DELAY + 1);
+        assert!(Governor::cast_vote(&mut instance, proposal_id, VoteType::For).is_ok());
+        assert_eq!(
+            Governor::queue(&mut instance, transactions(), description_hash),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+
+        advance_blocks(VOTING_PERIOD);
+        assert_eq!(Governor::state(&instance, proposal_id), Ok(ProposalState::Succeeded));
+        assert_eq!(
+            Governor::execute(&mut instance, transactions(), description_hash),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+
+        assert_eq!(
+            Governor::queue(&mut instance, transactions(), description_hash),
+            Ok(proposal_id)
+        );
+        assert_eq!(Governor::state(&instance, proposal_id), Ok(ProposalState::Queued));
+        assert_eq!(instance.scheduled, transactions());
+
+        assert_eq!(
+            Governor::execute(&mut instance, transactions(), description_hash),
+            Ok(proposal_id)
+        );
+        assert_eq!(Governor::state(&instance, proposal_id), Ok(ProposalState::Executed));
+        assert_eq!(instance.executed, transactions());
+    }
+
+    #[ink::test]
+    fn proposal_is_defeated() {
+        let accounts = accounts();
+        let mut instance = GovernorStruct::new(accounts.django, 0, 20);
+        let first = propose(&mut instance);
+        let second = Governor::propose(&mut instance, transactions(), String::from("Proposal #2")).unwrap();
+        advance_blocks(VOTING_DELAY + 1);
+
+        // the quorum is not reached
+        change_caller(accounts.charlie);
+        assert!(Governor::cast_vote(&mut instance, first, VoteType::For).is_ok());
+        // the majority is against
+        assert!(Governor::cast_vote(&mut instance, second, VoteType::For).is_ok());
+        change_caller(accounts.bob);
+        assert!(Governor::cast_vote(&mut instance, second, VoteType::Against).is_ok());
+
+        advance_blocks(VOTING_PERIOD);
+        assert_eq!(Governor::state(&instance, first), Ok(ProposalState::Defeated));
+        assert_eq!(Governor::state(&instance, second), Ok(ProposalState::Defeated));
+        let description_hash = description_hash(&instance);
+        assert_eq!(
+            Governor::queue(&mut instance, transactions(), description_hash),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+    }
+
+    #[ink::test]
+    fn cancel_works() {
+        let accounts = accounts();
+        let mut instance = GovernorStruct::new(accounts.django, 0, 20);
+        let proposal_id = propose(&mut instance);
+        let description_hash = description_hash(&instance);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            Governor::cancel(&mut instance, transactions(), description_hash),
+            Err(GovernorError::OnlyProposer)
+        );
+
+        change_caller(accounts.alice);
+        assert_eq!(
+            Governor::cancel(&mut instance, transactions(), description_hash),
+            Ok(proposal_id)
+        );
+        assert_eq!(Governor::state(&instance, proposal_id), Ok(ProposalState::Canceled));
+        assert_eq!(
+            Governor::cancel(&mut instance, transactions(), description_hash),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+    }
+
+    #[ink::test]
+    fn cancel_fails_after_voting_started() {
+        let mut instance = GovernorStruct::new(accounts().django, 0, 20);
+        propose(&mut instance);
+        let description_hash = description_hash(&instance);
+        advance_blocks(VOTING_DELAY + 1);
+
+        assert_eq!(
+            Governor::cancel(&mut instance, transactions(), description_hash),
+            Err(GovernorError::UnexpectedProposalState)
+        );
+    }
+
+    #[ink::test]
+    fn settings_are_changed_only_by_governance() {
+        let timelock = accounts().django;
+        let mut instance = GovernorStruct::new(timelock, 0, 20);
+
+        assert_eq!(
+            Governor::set_voting_delay(&mut instance, 10),
+            Err(GovernorError::OnlyGovernance)
+        );
+        assert_eq!(
+            Governor::update_quorum_numerator(&mut instance, 50),
+            Err(GovernorError::OnlyGovernance)
+        );
+
+        change_caller(timelock);
+        assert!(Governor::set_voting_delay(&mut instance, 10).is_ok());
+        assert!(Governor::set_voting_period(&mut instance, 20).is_ok());
+        assert!(Governor::set_proposal_threshold(&mut instance, 5).is_ok());
+        assert!(Governor::update_quorum_numerator(&mut instance, 50).is_ok());
+        assert_eq!(
+            Governor::set_voting_period(&mut instance, 0),
+            Err(GovernorError::InvalidVotingPeriod)
+        );
+        assert_eq!(
+            Governor::update_quorum_numerator(&mut instance, 101),
+            Err(GovernorError::InvalidQuorumFraction)
+        );
+
+        assert_eq!(Governor::voting_delay(&instance), 10);
+        assert_eq!(Governor::voting_period(&instance), 20);
+        assert_eq!(Governor::proposal_threshold(&instance), 5);
+        assert_eq!(Governor::quorum(&instance, 0), Ok(50));
+    }
+}
@@ -152,4 +152,13 @@ mod ownable {
             Err(OwnableError::CallerIsNotOwner)
         );
     }
+
+    #[ink::test]
+    fn transfer_ownership_to_zero_fails() {
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(
+            Ownable::transfer_ownership(&mut my_ownable, AccountId::from([0x0; 32])),
+            Err(OwnableError::NewOwnerIsZero)
+        );
+    }
 }
@@ -0,0 +1,179 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "ownable")]
+#[openbrush::implementation(Ownable, Ownable2Step)]
+#[openbrush::contract]
+mod ownable_2_step {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        previous: Option<AccountId>,
+        #[ink(topic)]
+        new: Option<AccountId>,
+    }
+
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        previous: Option<AccountId>,
+        #[ink(topic)]
+        new: AccountId,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyOwnable {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        ownable_2_step: ownable_2_step::Data,
+    }
+
+    type Event = <MyOwnable as ::ink::reflect::ContractEventBase>::Type;
+
+    impl MyOwnable {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut inst = Self::default();
+            ownable::Internal::_init_with_owner(&mut inst, Self::env().caller());
+            inst
+        }
+    }
+
+    #[overrider(ownable::Internal)]
+    fn _emit_ownership_transferred_event(&self, previous: Option<AccountId>, new: Option<AccountId>) {
+        self.env().emit_event(OwnershipTransferred { previous, new })
+    }
+
+    #[overrider(ownable_2_step::Internal)]
+    fn _emit_ownership_transfer_started_event(&self, previous: Option<AccountId>, new: AccountId) {
+        self.env().emit_event(OwnershipTransferStarted { previous, new })
+    }
+
+    #[ink::test]
+    fn transfer_ownership_sets_pending_owner() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+
+        assert_eq!(Ownable::transfer_ownership(&mut my_ownable, accounts.bob), Ok(()));
+        assert_eq!(Ownable::owner(&my_ownable), Some(accounts.alice));
+        assert_eq!(Ownable2Step::pending_owner(&my_ownable), Some(accounts.bob));
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(2, emitted_events.len());
+        let Event::OwnershipTransferStarted(OwnershipTransferStarted { previous, new }) =
+            <Event as scale::Decode>::decode(&mut &emitted_events[1].data[..])
+                .expect("encountered invalid contract event data buffer")
+        else {
+            panic!("encountered unexpected event kind: expected a OwnershipTransferStarted event")
+        };
+        assert_eq!(previous, Some(accounts.alice));
+        assert_eq!(new, accounts.bob);
+    }
+
+    #[ink::test]
+    fn accept_ownership_works() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(Ownable::transfer_ownership(&mut my_ownable, accounts.bob), Ok(()));
+
+        change_caller(accounts.bob);
+        assert_eq!(Ownable2Step::accept_ownership(&mut my_ownable), Ok(()));
+        assert_eq!(Ownable::owner(&my_ownable), Some(accounts.bob));
+        assert_eq!(Ownable2Step::pending_owner(&my_ownable), None);
+
+        let emitted_events = ink::env::test::recorded_events().collect::<Vec<_>>();
+        assert_eq!(3, emitted_events.len());
+        let Event::OwnershipTransferred(OwnershipTransferred { previous, new }) =
+            <Event as scale::Decode>::decode(&mut &emitted_events[2].data[..])
+                .expect("encountered invalid contract event data buffer")
+        else {
+            panic!("encountered unexpected event kind: expected a OwnershipTransferred event")
+        };
+        assert_eq!(previous, Some(accounts.alice));
+        assert_eq!(new, Some(accounts.bob));
+    }
+
+    #[ink::test]
+    fn accept_ownership_fails_if_not_pending_owner() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(
+            Ownable2Step::accept_ownership(&mut my_ownable),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+
+        assert_eq!(Ownable::transfer_ownership(&mut my_ownable, accounts.bob), Ok(()));
+        change_caller(accounts.charlie);
+        assert_eq!(
+            Ownable2Step::accept_ownership(&mut my_ownable),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+        assert_eq!(Ownable::owner(&my_ownable), Some(accounts.alice));
+    }
+
+    #[ink::test]
+    fn transfer_ownership_fails() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(
+            Ownable::transfer_ownership(&mut my_ownable, AccountId::from([0x0; 32])),
+            Err(OwnableError::NewOwnerIsZero)
+        );
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            Ownable::transfer_ownership(&mut my_ownable, accounts.bob),
+            Err(OwnableError::CallerIsNotOwner)
+        );
+        assert_eq!(Ownable2Step::pending_owner(&my_ownable), None);
+    }
+
+    #[ink::test]
+    fn renounce_ownership_cancels_pending_transfer() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+        assert_eq!(Ownable::transfer_ownership(&mut my_ownable, accounts.bob), Ok(()));
+
+        assert_eq!(Ownable::renounce_ownership(&mut my_ownable), Ok(()));
+        assert_eq!(Ownable::owner(&my_ownable), None);
+        assert_eq!(Ownable2Step::pending_owner(&my_ownable), None);
+
+        change_caller(accounts.bob);
+        assert_eq!(
+            Ownable2Step::accept_ownership(&mut my_ownable),
+            Err(OwnableError::CallerIsNotPendingOwner)
+        );
+    }
+}
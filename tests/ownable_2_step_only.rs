@@ -0,0 +1,65 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "ownable")]
+#[openbrush::implementation(Ownable2Step)]
+#[openbrush::contract]
+mod ownable_2_step_only {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MyOwnable {
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        ownable_2_step: ownable_2_step::Data,
+    }
+
+    impl MyOwnable {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn ownable_is_implemented_with_ownable_2_step() {
+        let accounts = accounts();
+        let mut my_ownable = MyOwnable::new();
+
+        assert_eq!(Ownable::transfer_ownership(&mut my_ownable, accounts.bob), Ok(()));
+        assert_eq!(Ownable::owner(&my_ownable), Some(accounts.alice));
+        assert_eq!(Ownable2Step::pending_owner(&my_ownable), Some(accounts.bob));
+
+        change_caller(accounts.bob);
+        assert_eq!(Ownable2Step::accept_ownership(&mut my_ownable), Ok(()));
+        assert_eq!(Ownable::owner(&my_ownable), Some(accounts.bob));
+    }
+}
@@ -270,6 +270,77 @@ mod payment_splitter {
         assert_payment_released_event(&emitted_events[3], accounts.bob, 2 * 333333);
     }
 
+    #[ink::test]
+    fn correct_payees_page() {
+        let accounts = accounts();
+        let instance = MySplitter::new(vec![
+            (accounts.alice, 100),
+            (accounts.bob, 200),
+            (accounts.charlie, 300),
+        ]);
+
+        assert_eq!(
+            vec![accounts.alice, accounts.bob],
+            PaymentSplitter::payees(&instance, 0, 2)
+        );
+        assert_eq!(vec![accounts.charlie], PaymentSplitter::payees(&instance, 2, 2));
+        assert_eq!(Vec::<AccountId>::new(), PaymentSplitter::payees(&instance, 3, 2));
+    }
+
+    #[ink::test]
+    fn correct_migrate_payees() {
+        let accounts = accounts();
+        let mut instance = MySplitter::default();
+        ink::env::set_contract_storage(
+            &payment_splitter::STORAGE_KEY_DATA_PAYEES,
+            &vec![accounts.alice, accounts.bob],
+        );
+
+        assert_eq!(None, PaymentSplitter::payee(&instance, 0));
+        assert!(payment_splitter::Internal::_migrate_payees(&mut instance).is_ok());
+        assert_eq!(
+            vec![accounts.alice, accounts.bob],
+            PaymentSplitter::payees(&instance, 0, 10)
+        );
+
+        // The old payees are removed, so the second call does nothing
+        assert!(payment_splitter::Internal::_migrate_payees(&mut instance).is_ok());
+        assert_eq!(2, PaymentSplitter::payees(&instance, 0, 10).len());
+    }
+
+    #[ink::test]
+    fn correct_init_token_values() {
+        let accounts = accounts();
+        let instance = MySplitter::new(vec![(accounts.alice, 100), (accounts.bob, 200)]);
+        let token = accounts.django;
+
+        assert_eq!(0, PaymentSplitter::total_released_token(&instance, token));
+        assert_eq!(0, PaymentSplitter::released_token(&instance, token, accounts.alice));
+        assert_eq!(0, PaymentSplitter::released_token(&instance, token, accounts.bob));
+    }
+
+    #[ink::test]
+    fn correct_release_token_unknown_account() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.alice, 100), (accounts.bob, 200)]);
+
+        assert_eq!(
+            Err(PaymentSplitterError::AccountHasNoShares),
+            PaymentSplitter::release_token(&mut instance, accounts.django, accounts.eve)
+        );
+    }
+
+    #[ink::test]
+    fn correct_release_token_not_tracked() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.alice, 100), (accounts.bob, 200)]);
+
+        assert_eq!(
+            Err(PaymentSplitterError::TokenIsNotTracked),
+            PaymentSplitter::release_token(&mut instance, accounts.django, accounts.alice)
+        );
+    }
+
     fn add_funds(account: AccountId, amount: Balance) {
         let balance = ink::env::balance::<ink::env::DefaultEnvironment>();
         ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account, balance + amount);
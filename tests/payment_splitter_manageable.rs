@@ -0,0 +1,378 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "payment_splitter")]
+#[openbrush::implementation(PaymentSplitter, Ownable, PaymentSplitterManageable)]
+#[openbrush::contract]
+mod payment_splitter_manageable {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        storage::Mapping,
+    };
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(event)]
+    pub struct PayeeRemoved {
+        pub account: AccountId,
+    }
+
+    #[ink(event)]
+    pub struct SharesUpdated {
+        pub account: AccountId,
+        pub shares: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MySplitter {
+        #[storage_field]
+        splitter: payment_splitter::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        // Balances of the PSP22 token, cross-contract calls are not supported in the off-chain environment
+        token_balances: Mapping<AccountId, Balance>,
+    }
+
+    impl MySplitter {
+        #[ink(constructor)]
+        pub fn new(payees_and_shares: Vec<(AccountId, Balance)>) -> Self {
+            let mut instance = Self::default();
+
+            payment_splitter::Internal::_init(&mut instance, payees_and_shares).unwrap();
+            ownable::Internal::_init_with_owner(&mut instance, Self::env().caller());
+
+            instance
+        }
+    }
+
+    #[overrider(payment_splitter::Internal)]
+    fn _emit_payee_removed_event(&self, account: AccountId) {
+        self.env().emit_event(PayeeRemoved { account })
+    }
+
+    #[overrider(payment_splitter::Internal)]
+    fn _emit_shares_updated_event(&self, account: AccountId, shares: Balance) {
+        self.env().emit_event(SharesUpdated { account, shares })
+    }
+
+    #[overrider(payment_splitter::Internal)]
+    fn _token_balance(&self, token: AccountId) -> Result<Balance, PaymentSplitterError> {
+        if token == broken_token() {
+            return Err(PaymentSplitterError::TokenBalanceFailed(token))
+        }
+        Ok(self.token_balances.get(self.env().account_id()).unwrap_or_default())
+    }
+
+    #[overrider(payment_splitter::Internal)]
+    fn _transfer_token(
+        &mut self,
+        _token: AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<(), PaymentSplitterError> {
+        let contract = self.env().account_id();
+        let balance = self.token_balances.get(contract).unwrap_or_default();
+        self.token_balances.insert(contract, &(balance - amount));
+        let balance = self.token_balances.get(to).unwrap_or_default();
+        self.token_balances.insert(to, &(balance + amount));
+        Ok(())
+    }
+
+    #[ink::test]
+    fn add_payee_settles_pending_payments() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100), (accounts.bob, 200)]);
+        reset_balances(&[accounts.charlie, accounts.bob, accounts.django]);
+        add_funds(instance.env().account_id(), 900000);
+
+        assert!(PaymentSplitterManageable::add_payee(&mut instance, accounts.django, 300).is_ok());
+        assert_eq!(0, balance_of(accounts.charlie));
+        assert_eq!(150000, PaymentSplitter::released(&instance, accounts.charlie));
+        assert_eq!(300000, PaymentSplitterManageable::owed(&instance, accounts.charlie));
+        assert_eq!(900000, PaymentSplitter::total_released(&instance));
+        assert_eq!(600, PaymentSplitter::total_shares(&instance));
+        assert_eq!(
+            vec![accounts.charlie, accounts.bob, accounts.django],
+            PaymentSplitter::payees(&instance, 0, 10)
+        );
+
+        add_funds(instance.env().account_id(), 600000);
+        assert!(PaymentSplitter::release(&mut instance, accounts.charlie).is_ok());
+        assert!(PaymentSplitter::release(&mut instance, accounts.bob).is_ok());
+        assert!(PaymentSplitter::release(&mut instance, accounts.django).is_ok());
+        assert_eq!(400000, balance_of(accounts.charlie));
+        assert_eq!(800000, balance_of(accounts.bob));
+        assert_eq!(300000, balance_of(accounts.django));
+        assert_eq!(0, PaymentSplitterManageable::owed(&instance, accounts.charlie));
+    }
+
+    #[ink::test]
+    fn add_payee_without_pending_payments() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100)]);
+
+        assert!(PaymentSplitterManageable::add_payee(&mut instance, accounts.bob, 100).is_ok());
+        assert_eq!(200, PaymentSplitter::total_shares(&instance));
+        assert_eq!(
+            Err(PaymentSplitterError::AlreadyHasShares),
+            PaymentSplitterManageable::add_payee(&mut instance, accounts.bob, 100)
+        );
+    }
+
+    #[ink::test]
+    fn add_payee_fails_above_limit() {
+        let payees = (0..payment_splitter::MAX_PAYEES as u8)
+            .map(|i| (AccountId::from([i; 32]), 1))
+            .collect::<Vec<_>>();
+        let mut instance = MySplitter::new(payees);
+
+        assert_eq!(
+            Err(PaymentSplitterError::TooManyPayees),
+            PaymentSplitterManageable::add_payee(&mut instance, AccountId::from([0xff; 32]), 1)
+        );
+    }
+
+    #[ink::test]
+    fn add_token_works() {
+        let mut instance = MySplitter::new(vec![(accounts().charlie, 100)]);
+
+        for i in 0..payment_splitter::MAX_TOKENS as u8 {
+            assert!(PaymentSplitterManageable::add_token(&mut instance, AccountId::from([i; 32])).is_ok());
+        }
+        // Already tracked token is skipped
+        assert!(PaymentSplitterManageable::add_token(&mut instance, AccountId::from([0; 32])).is_ok());
+        assert_eq!(
+            Err(PaymentSplitterError::TooManyTokens),
+            PaymentSplitterManageable::add_token(&mut instance, AccountId::from([0xff; 32]))
+        );
+    }
+
+    #[ink::test]
+    fn remove_token_works() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100)]);
+
+        assert!(PaymentSplitterManageable::add_token(&mut instance, accounts.django).is_ok());
+        assert!(PaymentSplitterManageable::remove_token(&mut instance, accounts.django).is_ok());
+        assert_eq!(
+            Err(PaymentSplitterError::TokenIsNotTracked),
+            PaymentSplitterManageable::remove_token(&mut instance, accounts.django)
+        );
+        assert_eq!(
+            Err(PaymentSplitterError::TokenIsNotTracked),
+            PaymentSplitter::release_token(&mut instance, accounts.django, accounts.charlie)
+        );
+    }
+
+    #[ink::test]
+    fn remove_payee_settles_pending_payments() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![
+            (accounts.charlie, 100),
+            (accounts.bob, 200),
+            (accounts.django, 300),
+        ]);
+        reset_balances(&[accounts.charlie, accounts.bob, accounts.django]);
+        add_funds(instance.env().account_id(), 600000);
+
+        assert!(PaymentSplitterManageable::remove_payee(&mut instance, accounts.charlie).is_ok());
+        assert_eq!(0, balance_of(accounts.charlie));
+        assert_eq!(500, PaymentSplitter::total_shares(&instance));
+        assert_eq!(0, PaymentSplitter::shares(&instance, accounts.charlie));
+        assert_eq!(
+            vec![accounts.django, accounts.bob],
+            PaymentSplitter::payees(&instance, 0, 10)
+        );
+
+        add_funds(instance.env().account_id(), 500000);
+        assert!(PaymentSplitter::release(&mut instance, accounts.charlie).is_ok());
+        assert_eq!(100000, balance_of(accounts.charlie));
+        assert_eq!(
+            Err(PaymentSplitterError::AccountHasNoShares),
+            PaymentSplitter::release(&mut instance, accounts.charlie)
+        );
+        assert!(PaymentSplitter::release(&mut instance, accounts.bob).is_ok());
+        assert!(PaymentSplitter::release(&mut instance, accounts.django).is_ok());
+        assert_eq!(400000, balance_of(accounts.bob));
+        assert_eq!(600000, balance_of(accounts.django));
+    }
+
+    #[ink::test]
+    fn remove_payee_fails() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100)]);
+
+        assert_eq!(
+            Err(PaymentSplitterError::AccountHasNoShares),
+            PaymentSplitterManageable::remove_payee(&mut instance, accounts.bob)
+        );
+        assert_eq!(
+            Err(PaymentSplitterError::NoPayees),
+            PaymentSplitterManageable::remove_payee(&mut instance, accounts.charlie)
+        );
+    }
+
+    #[ink::test]
+    fn update_shares_settles_pending_payments() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100), (accounts.bob, 100)]);
+        reset_balances(&[accounts.charlie, accounts.bob]);
+        add_funds(instance.env().account_id(), 200000);
+
+        assert!(PaymentSplitterManageable::update_shares(&mut instance, accounts.bob, 300).is_ok());
+        assert_eq!(0, balance_of(accounts.charlie));
+        assert_eq!(0, balance_of(accounts.bob));
+        assert_eq!(300, PaymentSplitter::shares(&instance, accounts.bob));
+        assert_eq!(400, PaymentSplitter::total_shares(&instance));
+
+        add_funds(instance.env().account_id(), 400000);
+        assert!(PaymentSplitter::release(&mut instance, accounts.charlie).is_ok());
+        assert!(PaymentSplitter::release(&mut instance, accounts.bob).is_ok());
+        assert_eq!(200000, balance_of(accounts.charlie));
+        assert_eq!(400000, balance_of(accounts.bob));
+    }
+
+    #[ink::test]
+    fn update_shares_settles_pending_token_payments() {
+        let accounts = accounts();
+        let token = token();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100), (accounts.bob, 100)]);
+        assert!(PaymentSplitterManageable::add_token(&mut instance, token).is_ok());
+        add_tokens(&mut instance, 1000);
+
+        assert!(PaymentSplitterManageable::update_shares(&mut instance, accounts.bob, 300).is_ok());
+        assert_eq!(0, instance.token_balances.get(accounts.charlie).unwrap_or_default());
+        assert_eq!(
+            500,
+            PaymentSplitterManageable::owed_token(&instance, token, accounts.charlie)
+        );
+
+        add_tokens(&mut instance, 400);
+        assert!(PaymentSplitter::release_token(&mut instance, token, accounts.charlie).is_ok());
+        assert!(PaymentSplitter::release_token(&mut instance, token, accounts.bob).is_ok());
+        assert_eq!(600, instance.token_balances.get(accounts.charlie).unwrap_or_default());
+        assert_eq!(800, instance.token_balances.get(accounts.bob).unwrap_or_default());
+        assert_eq!(0, PaymentSplitterManageable::owed_token(&instance, token, accounts.bob));
+        assert_eq!(
+            Err(PaymentSplitterError::AccountIsNotDuePayment),
+            PaymentSplitter::release_token(&mut instance, token, accounts.bob)
+        );
+    }
+
+    #[ink::test]
+    fn broken_token_blocks_payees_change_until_removed() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100), (accounts.bob, 100)]);
+        assert!(PaymentSplitterManageable::add_token(&mut instance, broken_token()).is_ok());
+
+        assert_eq!(
+            Err(PaymentSplitterError::TokenBalanceFailed(broken_token())),
+            PaymentSplitterManageable::update_shares(&mut instance, accounts.bob, 300)
+        );
+        assert!(PaymentSplitterManageable::remove_token(&mut instance, broken_token()).is_ok());
+        assert!(PaymentSplitterManageable::update_shares(&mut instance, accounts.bob, 300).is_ok());
+    }
+
+    #[ink::test]
+    fn update_shares_fails() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100)]);
+
+        assert_eq!(
+            Err(PaymentSplitterError::SharesAreZero),
+            PaymentSplitterManageable::update_shares(&mut instance, accounts.charlie, 0)
+        );
+        assert_eq!(
+            Err(PaymentSplitterError::AccountHasNoShares),
+            PaymentSplitterManageable::update_shares(&mut instance, accounts.bob, 100)
+        );
+    }
+
+    #[ink::test]
+    fn only_owner_manages_payees() {
+        let accounts = accounts();
+        let mut instance = MySplitter::new(vec![(accounts.charlie, 100), (accounts.bob, 100)]);
+        change_caller(accounts.bob);
+
+        let error = Err(PaymentSplitterError::Custom(String::from("O::CallerIsNotOwner")));
+        assert_eq!(
+            error,
+            PaymentSplitterManageable::add_payee(&mut instance, accounts.django, 100)
+        );
+        assert_eq!(
+            error,
+            PaymentSplitterManageable::remove_payee(&mut instance, accounts.charlie)
+        );
+        assert_eq!(
+            error,
+            PaymentSplitterManageable::update_shares(&mut instance, accounts.charlie, 200)
+        );
+        assert_eq!(
+            error,
+            PaymentSplitterManageable::add_token(&mut instance, accounts.django)
+        );
+        assert_eq!(
+            error,
+            PaymentSplitterManageable::remove_token(&mut instance, accounts.django)
+        );
+    }
+
+    fn token() -> AccountId {
+        AccountId::from([0x10; 32])
+    }
+
+    fn broken_token() -> AccountId {
+        AccountId::from([0x11; 32])
+    }
+
+    fn add_tokens(instance: &mut MySplitter, amount: Balance) {
+        let contract = instance.env().account_id();
+        let balance = instance.token_balances.get(contract).unwrap_or_default();
+        instance.token_balances.insert(contract, &(balance + amount));
+    }
+
+    fn reset_balances(accounts: &[AccountId]) {
+        for account in accounts {
+            ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(*account, 0);
+        }
+    }
+
+    fn balance_of(account: AccountId) -> Balance {
+        ink::env::test::get_account_balance::<ink::env::DefaultEnvironment>(account).unwrap()
+    }
+
+    fn add_funds(account: AccountId, amount: Balance) {
+        let balance = ink::env::balance::<ink::env::DefaultEnvironment>();
+        ink::env::test::set_account_balance::<ink::env::DefaultEnvironment>(account, balance + amount);
+    }
+}
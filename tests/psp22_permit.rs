@@ -0,0 +1,236 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22_permit")]
+#[openbrush::implementation(PSP22, PSP22Permit)]
+#[openbrush::contract]
+mod psp22_permit {
+    use ink::env::hash::{
+        Blake2x256,
+        HashOutput,
+    };
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+    use schnorrkel::{
+        ExpansionMode,
+        Keypair,
+        MiniSecretKey,
+    };
+    use secp256k1::{
+        Message,
+        PublicKey,
+        SecretKey,
+        SECP256K1,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        permit: permit::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            assert!(psp22::Internal::_mint_to(&mut instance, caller, total_supply).is_ok());
+            instance
+        }
+    }
+
+    type DefEnv = ink::env::DefaultEnvironment;
+
+    const DEADLINE: Timestamp = 1000;
+
+    fn secret_key(seed: u8) -> SecretKey {
+        SecretKey::from_slice(&[seed; 32]).unwrap()
+    }
+
+    /// The account of the ECDSA key is the hash of the compressed public key
+    fn account_of(secret_key: &SecretKey) -> AccountId {
+        let public_key = PublicKey::from_secret_key(SECP256K1, secret_key).serialize();
+        let mut output = <Blake2x256 as HashOutput>::Type::default();
+        ink::env::hash_bytes::<Blake2x256>(&public_key, &mut output);
+        AccountId::from(output)
+    }
+
+    fn sign(
+        token: &PSP22Struct,
+        secret_key: &SecretKey,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+    ) -> PermitSignature {
+        let nonce = PSP22Permit::nonces(token, owner);
+        let message_hash = permit::Internal::_permit_message_hash(token, &owner, &spender, &value, nonce, &deadline);
+        let (recovery_id, compact) = SECP256K1
+            .sign_ecdsa_recoverable(&Message::from_slice(&message_hash).unwrap(), secret_key)
+            .serialize_compact();
+
+        let mut signature = [0u8; 65];
+        signature[..64].copy_from_slice(&compact);
+        signature[64] = recovery_id.to_i32() as u8;
+        PermitSignature::Ecdsa(signature)
+    }
+
+    fn sr25519_keypair(seed: u8) -> Keypair {
+        MiniSecretKey::from_bytes(&[seed; 32])
+            .unwrap()
+            .expand_to_keypair(ExpansionMode::Ed25519)
+    }
+
+    /// The account of the sr25519 key is the public key
+    fn sr25519_account_of(keypair: &Keypair) -> AccountId {
+        AccountId::from(keypair.public.to_bytes())
+    }
+
+    fn sr25519_sign(
+        token: &PSP22Struct,
+        keypair: &Keypair,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        deadline: Timestamp,
+    ) -> PermitSignature {
+        let nonce = PSP22Permit::nonces(token, owner);
+        let message_hash = permit::Internal::_permit_message_hash(token, &owner, &spender, &value, nonce, &deadline);
+        let signature = keypair.sign_simple(permit::SR25519_SIGNING_CONTEXT, &message_hash);
+        PermitSignature::Sr25519(signature.to_bytes())
+    }
+
+    #[ink::test]
+    fn permit_works() {
+        let spender = accounts().bob;
+        let secret_key = secret_key(1);
+        let owner = account_of(&secret_key);
+        let mut token = PSP22Struct::new(100);
+
+        let signature = sign(&token, &secret_key, owner, spender, 50, DEADLINE);
+        assert!(PSP22Permit::permit(&mut token, owner, spender, 50, DEADLINE, signature).is_ok());
+
+        assert_eq!(PSP22::allowance(&token, owner, spender), 50);
+        assert_eq!(PSP22Permit::nonces(&token, owner), 1);
+    }
+
+    #[ink::test]
+    fn permit_works_with_sr25519_signature() {
+        let spender = accounts().bob;
+        let keypair = sr25519_keypair(1);
+        let owner = sr25519_account_of(&keypair);
+        let mut token = PSP22Struct::new(100);
+
+        let signature = sr25519_sign(&token, &keypair, owner, spender, 50, DEADLINE);
+        assert!(PSP22Permit::permit(&mut token, owner, spender, 50, DEADLINE, signature).is_ok());
+
+        assert_eq!(PSP22::allowance(&token, owner, spender), 50);
+        assert_eq!(PSP22Permit::nonces(&token, owner), 1);
+    }
+
+    #[ink::test]
+    fn permit_fails_with_sr25519_signature_of_other_account() {
+        let spender = accounts().bob;
+        let owner = sr25519_account_of(&sr25519_keypair(1));
+        let mut token = PSP22Struct::new(100);
+
+        let signature = sr25519_sign(&token, &sr25519_keypair(2), owner, spender, 50, DEADLINE);
+        assert_eq!(
+            PSP22Permit::permit(&mut token, owner, spender, 50, DEADLINE, signature),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+        assert_eq!(PSP22::allowance(&token, owner, spender), 0);
+    }
+
+    #[ink::test]
+    fn permit_fails_on_replay() {
+        let spender = accounts().bob;
+        let secret_key = secret_key(1);
+        let owner = account_of(&secret_key);
+        let mut token = PSP22Struct::new(100);
+
+        let signature = sign(&token, &secret_key, owner, spender, 50, DEADLINE);
+        assert!(PSP22Permit::permit(&mut token, owner, spender, 50, DEADLINE, signature).is_ok());
+
+        assert_eq!(
+            PSP22Permit::permit(&mut token, owner, spender, 50, DEADLINE, signature),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+    }
+
+    #[ink::test]
+    fn permit_fails_with_signature_of_other_account() {
+        let spender = accounts().bob;
+        let owner = account_of(&secret_key(1));
+        let mut token = PSP22Struct::new(100);
+
+        let signature = sign(&token, &secret_key(2), owner, spender, 50, DEADLINE);
+        assert_eq!(
+            PSP22Permit::permit(&mut token, owner, spender, 50, DEADLINE, signature),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+        assert_eq!(PSP22::allowance(&token, owner, spender), 0);
+    }
+
+    #[ink::test]
+    fn permit_fails_with_changed_value() {
+        let spender = accounts().bob;
+        let secret_key = secret_key(1);
+        let owner = account_of(&secret_key);
+        let mut token = PSP22Struct::new(100);
+
+        let signature = sign(&token, &secret_key, owner, spender, 50, DEADLINE);
+        assert_eq!(
+            PSP22Permit::permit(&mut token, owner, spender, 100, DEADLINE, signature),
+            Err(PSP22Error::PermitInvalidSignature)
+        );
+    }
+
+    #[ink::test]
+    fn permit_fails_after_deadline() {
+        let spender = accounts().bob;
+        let secret_key = secret_key(1);
+        let owner = account_of(&secret_key);
+        let mut token = PSP22Struct::new(100);
+
+        let signature = sign(&token, &secret_key, owner, spender, 50, DEADLINE);
+        ink::env::test::set_block_timestamp::<DefEnv>(DEADLINE + 1);
+
+        assert_eq!(
+            PSP22Permit::permit(&mut token, owner, spender, 50, DEADLINE, signature),
+            Err(PSP22Error::PermitExpired)
+        );
+    }
+
+    #[ink::test]
+    fn domain_separator_is_bound_to_contract() {
+        let token = PSP22Struct::new(100);
+        let domain_separator = PSP22Permit::domain_separator(&token);
+
+        ink::env::test::set_callee::<DefEnv>(accounts().django);
+        assert_ne!(PSP22Permit::domain_separator(&token), domain_separator);
+    }
+}
@@ -0,0 +1,114 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22)]
+#[openbrush::contract]
+mod psp22_receiver {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        safe_transfer_check: bool,
+    }
+
+    #[overrider(psp22::Internal)]
+    fn _is_safe_transfer_check_enabled(&self) -> bool {
+        self.safe_transfer_check
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance, safe_transfer_check: bool) -> Self {
+            let mut instance = Self {
+                safe_transfer_check,
+                ..Default::default()
+            };
+            let caller = instance.env().caller();
+            assert!(psp22::Internal::_mint_to(&mut instance, caller, total_supply).is_ok());
+            instance
+        }
+    }
+
+    #[ink::test]
+    fn safe_transfer_check_result_works() {
+        assert_eq!(psp22::safe_transfer_check_result(Ok(Ok(Ok(())))), Ok(()));
+        assert_eq!(
+            psp22::safe_transfer_check_result(Err(ink::env::Error::NotCallable)),
+            Ok(())
+        );
+        assert_eq!(
+            psp22::safe_transfer_check_result(Ok(Err(ink::LangError::CouldNotReadInput))),
+            Ok(())
+        );
+        assert_eq!(
+            psp22::safe_transfer_check_result(Ok(Ok(Err(PSP22ReceiverError::TransferRejected(String::from(
+                "Rejected"
+            )))))),
+            Err(PSP22Error::SafeTransferCheckFailed(String::from("Rejected")))
+        );
+        assert_eq!(
+            psp22::safe_transfer_check_result(Err(ink::env::Error::CalleeTrapped)),
+            Err(PSP22Error::SafeTransferCheckFailed(String::from(
+                "Error while performing the `before_received`"
+            )))
+        );
+    }
+
+    #[ink::test]
+    fn transfer_to_account_works() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100, true);
+
+        assert!(PSP22::transfer(&mut token, accounts.bob, 10, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&token, accounts.bob), 10);
+    }
+
+    #[ink::test]
+    fn receiver_is_not_called_when_check_is_disabled() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100, false);
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+
+        assert!(PSP22::transfer(&mut token, accounts.django, 10, vec![]).is_ok());
+        assert_eq!(PSP22::balance_of(&token, accounts.django), 10);
+    }
+
+    // The off-chain environment can't call the receiver, so the call is detected by the panic
+    #[ink::test]
+    #[should_panic(expected = "off-chain environment does not support contract invocation")]
+    fn receiver_is_called_when_check_is_enabled() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100, true);
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+
+        let _ = PSP22::transfer(&mut token, accounts.django, 10, vec![]);
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Snapshot, PSP22Mintable, PSP22Burnable)]
+#[openbrush::contract]
+mod psp22_snapshot {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        snapshot: snapshot::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            assert!(psp22::Internal::_mint_to(&mut instance, caller, total_supply).is_ok());
+            instance
+        }
+
+        #[ink(message)]
+        pub fn snapshot(&mut self) -> SnapshotId {
+            snapshot::Internal::_snapshot(self)
+        }
+    }
+
+    #[ink::test]
+    fn nonexistent_snapshot_fails() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+
+        assert_eq!(
+            PSP22Snapshot::balance_of_at(&token, accounts.alice, 0),
+            Err(PSP22Error::Custom(String::from("Nonexistent snapshot")))
+        );
+        assert_eq!(
+            PSP22Snapshot::total_supply_at(&token, 1),
+            Err(PSP22Error::Custom(String::from("Nonexistent snapshot")))
+        );
+
+        assert_eq!(token.snapshot(), 1);
+        assert!(PSP22Snapshot::total_supply_at(&token, 1).is_ok());
+        assert!(PSP22Snapshot::total_supply_at(&token, 2).is_err());
+    }
+
+    #[ink::test]
+    fn snapshot_without_changes_returns_current_values() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+        let id = token.snapshot();
+
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, id), Ok(100));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, id), Ok(0));
+        assert_eq!(PSP22Snapshot::total_supply_at(&token, id), Ok(100));
+    }
+
+    #[ink::test]
+    fn transfer_keeps_values_of_snapshot() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+        let first = token.snapshot();
+
+        assert!(PSP22::transfer(&mut token, accounts.bob, 30, vec![]).is_ok());
+        assert!(PSP22::transfer(&mut token, accounts.bob, 10, vec![]).is_ok());
+        let second = token.snapshot();
+        assert!(PSP22::transfer(&mut token, accounts.charlie, 60, vec![]).is_ok());
+
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, first), Ok(100));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, first), Ok(0));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, second), Ok(60));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, second), Ok(40));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.charlie, second), Ok(0));
+        assert_eq!(PSP22::balance_of(&token, accounts.charlie), 60);
+    }
+
+    #[ink::test]
+    fn snapshots_without_changes_between_them_share_values() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+        let first = token.snapshot();
+        let second = token.snapshot();
+        let third = token.snapshot();
+
+        assert!(PSP22::transfer(&mut token, accounts.bob, 30, vec![]).is_ok());
+
+        for id in [first, second, third] {
+            assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, id), Ok(100));
+            assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, id), Ok(0));
+        }
+    }
+
+    #[ink::test]
+    fn mint_and_burn_update_total_supply() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+        let first = token.snapshot();
+
+        assert!(PSP22Mintable::mint(&mut token, accounts.bob, 50).is_ok());
+        let second = token.snapshot();
+        assert!(PSP22Burnable::burn(&mut token, accounts.alice, 20).is_ok());
+
+        assert_eq!(PSP22Snapshot::total_supply_at(&token, first), Ok(100));
+        assert_eq!(PSP22Snapshot::total_supply_at(&token, second), Ok(150));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, first), Ok(0));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.bob, second), Ok(50));
+        assert_eq!(PSP22Snapshot::balance_of_at(&token, accounts.alice, second), Ok(100));
+        assert_eq!(PSP22::total_supply(&token), 130);
+    }
+}
@@ -0,0 +1,169 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp22")]
+#[openbrush::implementation(PSP22, PSP22Votes, PSP22Mintable, PSP22Burnable)]
+#[openbrush::contract]
+mod psp22_votes {
+    use openbrush::{
+        test_utils::{
+            accounts,
+            change_caller,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP22Struct {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        votes: votes::Data,
+    }
+
+    impl PSP22Struct {
+        #[ink(constructor)]
+        pub fn new(total_supply: Balance) -> Self {
+            let mut instance = Self::default();
+            let caller = instance.env().caller();
+            assert!(psp22::Internal::_mint_to(&mut instance, caller, total_supply).is_ok());
+            instance
+        }
+    }
+
+    type DefEnv = ink::env::DefaultEnvironment;
+
+    fn advance_block() {
+        ink::env::test::advance_block::<DefEnv>();
+    }
+
+    fn current_block() -> BlockNumber {
+        ink::env::block_number::<DefEnv>()
+    }
+
+    #[ink::test]
+    fn votes_are_zero_without_delegation() {
+        let accounts = accounts();
+        let token = PSP22Struct::new(100);
+
+        assert_eq!(PSP22Votes::delegates(&token, accounts.alice), None);
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.alice), 0);
+    }
+
+    #[ink::test]
+    fn self_delegation_works() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+
+        assert!(PSP22Votes::delegate(&mut token, accounts.alice).is_ok());
+
+        assert_eq!(PSP22Votes::delegates(&token, accounts.alice), Some(accounts.alice));
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.alice), 100);
+    }
+
+    #[ink::test]
+    fn redelegation_moves_votes() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+
+        assert!(PSP22Votes::delegate(&mut token, accounts.bob).is_ok());
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.bob), 100);
+
+        assert!(PSP22Votes::delegate(&mut token, accounts.charlie).is_ok());
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.bob), 0);
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.charlie), 100);
+    }
+
+    #[ink::test]
+    fn transfer_moves_votes() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+
+        assert!(PSP22Votes::delegate(&mut token, accounts.alice).is_ok());
+        change_caller(accounts.bob);
+        assert!(PSP22Votes::delegate(&mut token, accounts.charlie).is_ok());
+        change_caller(accounts.alice);
+
+        assert!(PSP22::transfer(&mut token, accounts.bob, 30, Vec::<u8>::new()).is_ok());
+
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.alice), 70);
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.bob), 0);
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.charlie), 30);
+    }
+
+    #[ink::test]
+    fn mint_and_burn_update_votes_and_supply() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+
+        assert!(PSP22Votes::delegate(&mut token, accounts.alice).is_ok());
+        assert!(PSP22Mintable::mint(&mut token, accounts.alice, 50).is_ok());
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.alice), 150);
+
+        assert!(PSP22Burnable::burn(&mut token, accounts.alice, 20).is_ok());
+        assert_eq!(PSP22Votes::get_votes(&token, accounts.alice), 130);
+
+        advance_block();
+        assert_eq!(PSP22Votes::get_past_total_supply(&token, current_block() - 1), Ok(130));
+    }
+
+    #[ink::test]
+    fn past_votes_work() {
+        let accounts = accounts();
+        let mut token = PSP22Struct::new(100);
+        let first_block = current_block();
+
+        assert!(PSP22Votes::delegate(&mut token, accounts.alice).is_ok());
+        advance_block();
+        let second_block = current_block();
+
+        assert!(PSP22::transfer(&mut token, accounts.bob, 40, Vec::<u8>::new()).is_ok());
+        advance_block();
+        advance_block();
+
+        assert_eq!(PSP22Votes::get_past_votes(&token, accounts.alice, first_block), Ok(100));
+        assert_eq!(PSP22Votes::get_past_votes(&token, accounts.alice, second_block), Ok(60));
+        assert_eq!(
+            PSP22Votes::get_past_votes(&token, accounts.alice, second_block + 1),
+            Ok(60)
+        );
+        assert_eq!(PSP22Votes::get_past_total_supply(&token, first_block), Ok(100));
+    }
+
+    #[ink::test]
+    fn past_lookup_fails_for_current_block() {
+        let accounts = accounts();
+        let token = PSP22Struct::new(100);
+
+        assert_eq!(
+            PSP22Votes::get_past_votes(&token, accounts.alice, current_block()),
+            Err(PSP22Error::Custom(String::from("Block not yet mined")))
+        );
+        assert_eq!(
+            PSP22Votes::get_past_total_supply(&token, current_block()),
+            Err(PSP22Error::Custom(String::from("Block not yet mined")))
+        );
+    }
+}
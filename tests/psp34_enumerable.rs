@@ -176,4 +176,51 @@ mod psp34_enumerable {
             Err(PSP34Error::TokenNotExists)
         );
     }
+
+    #[ink::test]
+    fn enumerable_pagination_works() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        for id in 0..5u8 {
+            assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U8(id)).is_ok());
+        }
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts.bob, Id::U8(5u8)).is_ok());
+
+        assert_eq!(
+            PSP34Enumerable::tokens_of_owner(&nft, accounts.alice, 1, 2),
+            vec![Id::U8(1u8), Id::U8(2u8)]
+        );
+        // the page is cut at the end of the list
+        assert_eq!(
+            PSP34Enumerable::tokens_of_owner(&nft, accounts.alice, 3, 10),
+            vec![Id::U8(3u8), Id::U8(4u8)]
+        );
+        assert_eq!(PSP34Enumerable::tokens_of_owner(&nft, accounts.alice, 5, 10), vec![]);
+        assert_eq!(
+            PSP34Enumerable::tokens_of_owner(&nft, accounts.bob, 0, 10),
+            vec![Id::U8(5u8)]
+        );
+        assert_eq!(PSP34Enumerable::tokens(&nft, 4, 10), vec![Id::U8(4u8), Id::U8(5u8)]);
+    }
+
+    #[ink::test]
+    fn enumerable_page_size_is_bounded() {
+        let accounts = accounts();
+        // Create a new contract instance.
+        let mut nft = PSP34Struct::new();
+        for id in 0..(MAX_PAGE_SIZE as u16 + 1) {
+            assert!(psp34::Internal::_mint_to(&mut nft, accounts.alice, Id::U16(id)).is_ok());
+        }
+
+        assert_eq!(
+            PSP34Enumerable::tokens_of_owner(&nft, accounts.alice, 0, u128::MAX).len() as u128,
+            MAX_PAGE_SIZE
+        );
+        assert_eq!(PSP34Enumerable::tokens(&nft, u128::MAX, u128::MAX), vec![]);
+        assert_eq!(
+            PSP34Enumerable::tokens(&nft, MAX_PAGE_SIZE, u128::MAX),
+            vec![Id::U16(MAX_PAGE_SIZE as u16)]
+        );
+    }
 }
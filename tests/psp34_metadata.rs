@@ -23,9 +23,12 @@
 #[openbrush::implementation(PSP34, PSP34Metadata)]
 #[openbrush::contract]
 mod psp34_metadata {
-    use openbrush::traits::{
-        Storage,
-        String,
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
     };
 
     #[derive(Default, Storage)]
@@ -56,4 +59,72 @@ mod psp34_metadata {
             Some(String::from("VAL"))
         );
     }
+
+    #[ink::test]
+    fn token_uri_appends_id_to_base_uri() {
+        let mut nft = PSP34Struct::new(Id::U8(1u8), String::from("KEY"), String::from("VAL"));
+        assert_eq!(PSP34Metadata::base_uri(&nft), None);
+        assert_eq!(PSP34Metadata::token_uri(&nft, Id::U8(1u8)), None);
+
+        metadata::Internal::_set_base_uri(&mut nft, String::from("ipfs://collection/"));
+        for id in [Id::U8(7), Id::U128(u128::MAX), Id::Bytes(vec![0x0a, 0xff, 0x01])] {
+            assert!(psp34::Internal::_mint_to(&mut nft, accounts().alice, id).is_ok());
+        }
+
+        assert_eq!(PSP34Metadata::base_uri(&nft), Some(String::from("ipfs://collection/")));
+        assert_eq!(
+            PSP34Metadata::token_uri(&nft, Id::U8(7)),
+            Some(String::from("ipfs://collection/7"))
+        );
+        assert_eq!(
+            PSP34Metadata::token_uri(&nft, Id::U128(u128::MAX)),
+            Some(String::from(
+                "ipfs://collection/340282366920938463463374607431768211455"
+            ))
+        );
+        assert_eq!(
+            PSP34Metadata::token_uri(&nft, Id::Bytes(vec![0x0a, 0xff, 0x01])),
+            Some(String::from("ipfs://collection/0aff01"))
+        );
+    }
+
+    #[ink::test]
+    fn token_uri_override_works() {
+        let mut nft = PSP34Struct::new(Id::U8(1u8), String::from("KEY"), String::from("VAL"));
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts().alice, Id::U16(2)).is_ok());
+        metadata::Internal::_set_token_uri(&mut nft, Id::U16(2), String::from("ipfs://special"));
+
+        assert_eq!(
+            PSP34Metadata::token_uri(&nft, Id::U16(2)),
+            Some(String::from("ipfs://special"))
+        );
+
+        metadata::Internal::_set_base_uri(&mut nft, String::from("ipfs://collection/"));
+        assert_eq!(
+            PSP34Metadata::token_uri(&nft, Id::U16(2)),
+            Some(String::from("ipfs://special"))
+        );
+
+        metadata::Internal::_remove_token_uri(&mut nft, Id::U16(2));
+        assert_eq!(
+            PSP34Metadata::token_uri(&nft, Id::U16(2)),
+            Some(String::from("ipfs://collection/2"))
+        );
+    }
+
+    #[ink::test]
+    fn token_uri_of_unminted_token_is_none() {
+        let mut nft = PSP34Struct::new(Id::U8(1u8), String::from("KEY"), String::from("VAL"));
+        metadata::Internal::_set_base_uri(&mut nft, String::from("ipfs://collection/"));
+        metadata::Internal::_set_token_uri(&mut nft, Id::U8(2), String::from("ipfs://special"));
+
+        assert_eq!(PSP34Metadata::token_uri(&nft, Id::U8(1)), None);
+        assert_eq!(PSP34Metadata::token_uri(&nft, Id::U8(2)), None);
+
+        assert!(psp34::Internal::_mint_to(&mut nft, accounts().alice, Id::U8(1)).is_ok());
+        assert_eq!(
+            PSP34Metadata::token_uri(&nft, Id::U8(1)),
+            Some(String::from("ipfs://collection/1"))
+        );
+    }
 }
@@ -0,0 +1,89 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Mintable)]
+#[openbrush::contract]
+mod psp34_receiver {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn rejected_transfer_is_converted_to_psp34_error() {
+        assert_eq!(
+            psp34::safe_transfer_check_result(Ok(Ok(Err(PSP34ReceiverError::TransferRejected(String::from(
+                "Rejected"
+            )))))),
+            Err(PSP34Error::SafeTransferCheckFailed(String::from("Rejected")))
+        );
+    }
+
+    #[ink::test]
+    fn transfer_to_account_works() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+
+        assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U8(1)).is_ok());
+        assert!(PSP34::transfer(&mut nft, accounts.bob, Id::U8(1), vec![]).is_ok());
+        assert_eq!(PSP34::owner_of(&nft, Id::U8(1)), Some(accounts.bob));
+    }
+
+    // The off-chain environment can't call the receiver, so the call is detected by the panic
+    #[ink::test]
+    #[should_panic(expected = "off-chain environment does not support contract invocation")]
+    fn receiver_is_called_on_transfer() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U8(1)).is_ok());
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+
+        let _ = PSP34::transfer(&mut nft, accounts.django, Id::U8(1), vec![]);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "off-chain environment does not support contract invocation")]
+    fn receiver_is_called_on_mint() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new();
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+
+        let _ = PSP34Mintable::mint(&mut nft, accounts.django, Id::U8(1));
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp34")]
+#[openbrush::implementation(PSP34, PSP34Mintable, PSP34Burnable, PSP34Royalty)]
+#[openbrush::contract]
+mod psp34_royalty {
+    use openbrush::{
+        test_utils::accounts,
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP34Struct {
+        #[storage_field]
+        psp34: psp34::Data,
+        #[storage_field]
+        royalty: royalty::Data,
+    }
+
+    impl PSP34Struct {
+        #[ink(constructor)]
+        pub fn new(receiver: AccountId, fee_numerator: u128) -> Self {
+            let mut instance = Self::default();
+            royalty::Internal::_set_default_royalty(&mut instance, receiver, fee_numerator).unwrap();
+            instance
+        }
+
+        #[ink(message)]
+        pub fn set_token_royalty(
+            &mut self,
+            id: Id,
+            receiver: AccountId,
+            fee_numerator: u128,
+        ) -> Result<(), PSP34Error> {
+            royalty::Internal::_set_token_royalty(self, &id, receiver, fee_numerator)
+        }
+    }
+
+    #[ink::test]
+    fn default_royalty_works() {
+        let accounts = accounts();
+        // 2.5%
+        let nft = PSP34Struct::new(accounts.bob, 250);
+
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000), (accounts.bob, 250));
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(2), 1_000), (accounts.bob, 25));
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(2), u128::MAX),
+            (accounts.bob, u128::MAX / 40)
+        );
+    }
+
+    #[ink::test]
+    fn no_royalty_works() {
+        let mut nft = PSP34Struct::new(accounts().bob, 250);
+        royalty::Internal::_delete_default_royalty(&mut nft);
+
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000),
+            (AccountId::from([0x0; 32]), 0)
+        );
+    }
+
+    #[ink::test]
+    fn token_royalty_overrides_default() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.bob, 250);
+
+        assert!(nft.set_token_royalty(Id::U8(1), accounts.charlie, 1_000).is_ok());
+
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000),
+            (accounts.charlie, 1_000)
+        );
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(2), 10_000), (accounts.bob, 250));
+
+        royalty::Internal::_reset_token_royalty(&mut nft, &Id::U8(1));
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000), (accounts.bob, 250));
+    }
+
+    #[ink::test]
+    fn royalty_above_denominator_fails() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.bob, 250);
+
+        assert_eq!(
+            nft.set_token_royalty(Id::U8(1), accounts.charlie, 10_001),
+            Err(PSP34Error::RoyaltyFeeExceedsSalePrice)
+        );
+        assert_eq!(
+            royalty::Internal::_set_default_royalty(&mut nft, accounts.charlie, 10_001),
+            Err(PSP34Error::RoyaltyFeeExceedsSalePrice)
+        );
+    }
+
+    #[ink::test]
+    fn royalty_to_zero_address_fails() {
+        let mut nft = PSP34Struct::new(accounts().bob, 250);
+
+        assert_eq!(
+            nft.set_token_royalty(Id::U8(1), AccountId::from([0x0; 32]), 1_000),
+            Err(PSP34Error::RoyaltyReceiverIsZero)
+        );
+        assert_eq!(
+            royalty::Internal::_set_default_royalty(&mut nft, AccountId::from([0x0; 32]), 1_000),
+            Err(PSP34Error::RoyaltyReceiverIsZero)
+        );
+    }
+
+    #[ink::test]
+    fn burn_clears_token_royalty() {
+        let accounts = accounts();
+        let mut nft = PSP34Struct::new(accounts.bob, 250);
+        assert!(PSP34Mintable::mint(&mut nft, accounts.alice, Id::U8(1)).is_ok());
+        assert!(nft.set_token_royalty(Id::U8(1), accounts.charlie, 1_000).is_ok());
+
+        // transfer keeps the royalty of the token
+        assert!(PSP34::transfer(&mut nft, accounts.django, Id::U8(1), vec![]).is_ok());
+        assert_eq!(
+            PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000),
+            (accounts.charlie, 1_000)
+        );
+
+        assert!(PSP34Burnable::burn(&mut nft, accounts.django, Id::U8(1)).is_ok());
+        assert_eq!(PSP34Royalty::royalty_info(&nft, Id::U8(1), 10_000), (accounts.bob, 250));
+    }
+}
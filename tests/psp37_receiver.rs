@@ -0,0 +1,103 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(feature = "psp37")]
+#[openbrush::implementation(PSP37, PSP37Batch, PSP37Mintable)]
+#[openbrush::contract]
+mod psp37_receiver {
+    use openbrush::{
+        test_utils::accounts,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PSP37Struct {
+        #[storage_field]
+        psp37: psp37::Data,
+    }
+
+    impl PSP37Struct {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn rejected_transfer_is_converted_to_psp37_error() {
+        assert_eq!(
+            psp37::safe_transfer_check_result(Ok(Ok(Err(PSP37ReceiverError::TransferRejected(String::from(
+                "Unsupported id"
+            )))))),
+            Err(PSP37Error::SafeTransferCheckFailed(String::from("Unsupported id")))
+        );
+    }
+
+    #[ink::test]
+    fn batch_transfer_to_account_works() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        let ids_amounts = vec![(Id::U8(1), 10), (Id::U8(2), 20)];
+
+        assert!(PSP37Mintable::mint(&mut nft, accounts.alice, ids_amounts.clone()).is_ok());
+        assert!(PSP37Batch::batch_transfer(&mut nft, accounts.bob, ids_amounts, vec![]).is_ok());
+        assert_eq!(PSP37::balance_of(&nft, accounts.bob, Some(Id::U8(1))), 10);
+        assert_eq!(PSP37::balance_of(&nft, accounts.bob, Some(Id::U8(2))), 20);
+    }
+
+    // The off-chain environment can't call the receiver, so the call is detected by the panic
+    #[ink::test]
+    #[should_panic(expected = "off-chain environment does not support contract invocation")]
+    fn receiver_is_called_on_transfer() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        assert!(PSP37Mintable::mint(&mut nft, accounts.alice, vec![(Id::U8(1), 10)]).is_ok());
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+
+        let _ = PSP37::transfer(&mut nft, accounts.django, Id::U8(1), 10, vec![]);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "off-chain environment does not support contract invocation")]
+    fn receiver_is_called_on_batch_transfer() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        let ids_amounts = vec![(Id::U8(1), 10), (Id::U8(2), 20)];
+        assert!(PSP37Mintable::mint(&mut nft, accounts.alice, ids_amounts.clone()).is_ok());
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+
+        let _ = PSP37Batch::batch_transfer(&mut nft, accounts.django, ids_amounts, vec![]);
+    }
+
+    #[ink::test]
+    #[should_panic(expected = "off-chain environment does not support contract invocation")]
+    fn receiver_is_called_on_mint() {
+        let accounts = accounts();
+        let mut nft = PSP37Struct::new();
+        ink::env::test::set_contract::<ink::env::DefaultEnvironment>(accounts.django);
+
+        let _ = PSP37Mintable::mint(&mut nft, accounts.django, vec![(Id::U8(1), 10), (Id::U8(2), 20)]);
+    }
+}
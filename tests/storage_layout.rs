@@ -0,0 +1,70 @@
+// Copyright (c) 2012-2022 Supercolony
+//
+// Permission is hereby granted, free of charge, to any person obtaining
+// a copy of this software and associated documentation files (the"Software"),
+// to deal in the Software without restriction, including
+// without limitation the rights to use, copy, modify, merge, publish,
+// distribute, sublicense, and/or sell copies of the Software, and to
+// permit persons to whom the Software is furnished to do so, subject to
+// the following conditions:
+//
+// The above copyright notice and this permission notice shall be
+// included in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND,
+// EXPRESS OR IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF
+// MERCHANTABILITY, FITNESS FOR A PARTICULAR PURPOSE AND
+// NONINFRINGEMENT. IN NO EVENT SHALL THE AUTHORS OR COPYRIGHT HOLDERS BE
+// LIABLE FOR ANY CLAIM, DAMAGES OR OTHER LIABILITY, WHETHER IN AN ACTION
+// OF CONTRACT, TORT OR OTHERWISE, ARISING FROM, OUT OF OR IN CONNECTION
+// WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE SOFTWARE.
+
+#[cfg(all(feature = "psp22", feature = "ownable"))]
+#[openbrush::implementation(PSP22, Ownable)]
+#[openbrush::contract]
+mod storage_layout {
+    use openbrush::{
+        storage::layout::{
+            diff,
+            snapshot,
+            LayoutSnapshot,
+        },
+        traits::Storage,
+    };
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+    }
+
+    #[ink::test]
+    fn storage_layout_snapshot_works() {
+        let snapshot = snapshot::<Contract>();
+        let entries = LayoutSnapshot::from_json(&snapshot).unwrap().entries;
+
+        let owner = entries
+            .iter()
+            .find(|entry| entry.path == "ownable::owner::Some::0")
+            .unwrap();
+        assert_eq!(owner.key, ownable::STORAGE_KEY_DATA_OWNER);
+        assert_eq!(owner.key_type, None);
+
+        let balances = entries.iter().find(|entry| entry.path == "psp22::balances").unwrap();
+        assert_eq!(balances.key, psp22::STORAGE_KEY_DATA_BALANCES);
+        assert_eq!(balances.type_name, "u128");
+        assert_eq!(balances.key_type.as_deref(), Some("ink_primitives::types::AccountId"));
+
+        assert_eq!(diff(&snapshot, &snapshot).unwrap(), vec![]);
+    }
+}
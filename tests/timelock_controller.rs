@@ -60,6 +60,7 @@ mod timelock_controller {
         #[ink(topic)]
         pub index: u8,
         pub transaction: Transaction,
+        pub output: Vec<u8>,
     }
 
     /// Emitted when operation `id` is cancelled.
@@ -118,8 +119,13 @@ mod timelock_controller {
     }
 
     #[overrider(timelock_controller::Internal)]
-    fn _emit_call_executed_event(&self, id: OperationId, index: u8, transaction: Transaction) {
-        self.env().emit_event(CallExecuted { id, index, transaction })
+    fn _emit_call_executed_event(&self, id: OperationId, index: u8, transaction: Transaction, output: Vec<u8>) {
+        self.env().emit_event(CallExecuted {
+            id,
+            index,
+            transaction,
+            output,
+        })
     }
 
     impl TimelockControllerStruct {
@@ -438,4 +444,210 @@ mod timelock_controller {
             TimelockController::update_delay(&mut timelock, min_delay + 2)
         );
     }
+
+    #[ink::test]
+    fn should_return_operation_state() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+
+        let id = TimelockController::hash_operation(&timelock, Transaction::default(), None, [0; 32]);
+        assert_eq!(
+            TimelockController::get_operation_state(&timelock, id),
+            OperationState::Unset
+        );
+
+        assert!(TimelockController::schedule(&mut timelock, Transaction::default(), None, [0; 32], min_delay).is_ok());
+        assert_eq!(
+            TimelockController::get_operation_state(&timelock, id),
+            OperationState::Waiting
+        );
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(min_delay);
+        assert_eq!(
+            TimelockController::get_operation_state(&timelock, id),
+            OperationState::Ready
+        );
+
+        assert!(timelock_controller::Internal::_after_call(&mut timelock, id).is_ok());
+        assert_eq!(
+            TimelockController::get_operation_state(&timelock, id),
+            OperationState::Done
+        );
+        assert_eq!(TimelockController::pending_operations_count(&timelock), 0);
+    }
+
+    #[ink::test]
+    fn should_return_cancelled_operation_state() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+
+        let id = TimelockController::hash_operation(&timelock, Transaction::default(), None, [0; 32]);
+        assert!(TimelockController::schedule(&mut timelock, Transaction::default(), None, [0; 32], min_delay).is_ok());
+        assert!(TimelockController::cancel(&mut timelock, id).is_ok());
+
+        assert_eq!(
+            TimelockController::get_operation_state(&timelock, id),
+            OperationState::Cancelled
+        );
+        assert_eq!(TimelockController::pending_operations_count(&timelock), 0);
+    }
+
+    #[ink::test]
+    fn should_store_scheduled_operations() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+        let transactions = vec![Transaction::default(), Transaction::default()];
+
+        let id = TimelockController::hash_operation(&timelock, Transaction::default(), None, [0; 32]);
+        let batch_id = TimelockController::hash_operation_batch(&timelock, transactions.clone(), Some(id), [1; 32]);
+        assert!(TimelockController::schedule(&mut timelock, Transaction::default(), None, [0; 32], min_delay).is_ok());
+        assert!(
+            TimelockController::schedule_batch(&mut timelock, transactions.clone(), Some(id), [1; 32], min_delay)
+                .is_ok()
+        );
+
+        assert_eq!(
+            TimelockController::get_operation(&timelock, batch_id),
+            Some(Operation {
+                transactions,
+                predecessor: Some(id),
+                salt: [1; 32],
+            })
+        );
+        assert_eq!(TimelockController::pending_operations_count(&timelock), 2);
+        assert_eq!(
+            TimelockController::pending_operations(&timelock, 0, 10),
+            vec![id, batch_id]
+        );
+        assert_eq!(TimelockController::pending_operations(&timelock, 1, 10), vec![batch_id]);
+        assert_eq!(TimelockController::pending_operations(&timelock, 0, 1), vec![id]);
+
+        assert!(TimelockController::cancel(&mut timelock, id).is_ok());
+        assert_eq!(TimelockController::pending_operations(&timelock, 0, 10), vec![batch_id]);
+    }
+
+    #[ink::test]
+    fn should_update_grace_period() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+        assert_eq!(TimelockController::get_grace_period(&timelock), 0);
+
+        // Caller of the method is contract itself
+        change_caller(timelock.env().account_id());
+        assert!(TimelockController::update_grace_period(&mut timelock, 100).is_ok());
+        assert_eq!(TimelockController::get_grace_period(&timelock), 100);
+    }
+
+    #[ink::test]
+    fn should_update_grace_period_not_timelock_role() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+        change_caller([13; 32].into());
+
+        assert_eq!(
+            Err(TimelockControllerError::CallerMustBeTimeLock),
+            TimelockController::update_grace_period(&mut timelock, 100)
+        );
+    }
+
+    #[ink::test]
+    fn should_expire_operation_after_grace_period() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+
+        let id = TimelockController::hash_operation(&timelock, Transaction::default(), None, [0; 32]);
+        assert!(TimelockController::schedule(&mut timelock, Transaction::default(), None, [0; 32], min_delay).is_ok());
+
+        change_caller(timelock.env().account_id());
+        assert!(TimelockController::update_grace_period(&mut timelock, 100).is_ok());
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(min_delay + 99);
+        assert!(TimelockController::is_operation_ready(&timelock, id));
+        assert!(!TimelockController::is_operation_expired(&timelock, id));
+
+        ink::env::test::set_block_timestamp::<DefaultEnvironment>(min_delay + 100);
+        assert!(!TimelockController::is_operation_ready(&timelock, id));
+        assert!(TimelockController::is_operation_expired(&timelock, id));
+        assert_eq!(
+            TimelockController::get_operation_state(&timelock, id),
+            OperationState::Expired
+        );
+        assert_eq!(
+            Err(TimelockControllerError::OperationExpired),
+            timelock_controller::Internal::_after_call(&mut timelock, id)
+        );
+    }
+
+    #[ink::test]
+    fn should_check_transferred_value() {
+        let accounts = setup();
+        let min_delay = 10;
+        let timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+        let available = 1000;
+        ink::env::test::set_account_balance::<DefaultEnvironment>(
+            timelock.env().account_id(),
+            timelock.env().minimum_balance() + available,
+        );
+
+        let transaction = Transaction {
+            transferred_value: available / 2,
+            ..Default::default()
+        };
+        assert_eq!(
+            timelock_controller::Internal::_check_transferred_value(
+                &timelock,
+                &[transaction.clone(), transaction.clone()]
+            ),
+            Ok(available / 2 * 2)
+        );
+        assert_eq!(
+            timelock_controller::Internal::_check_transferred_value(
+                &timelock,
+                &[transaction.clone(), transaction.clone(), transaction]
+            ),
+            Err(TimelockControllerError::InsufficientBalance)
+        );
+
+        let transaction = Transaction {
+            transferred_value: Balance::MAX,
+            ..Default::default()
+        };
+        assert_eq!(
+            timelock_controller::Internal::_check_transferred_value(&timelock, &[transaction.clone(), transaction]),
+            Err(TimelockControllerError::InsufficientBalance)
+        );
+    }
+
+    #[ink::test]
+    fn should_refund_unspent_value() {
+        let accounts = setup();
+        let min_delay = 10;
+        let mut timelock = TimelockControllerStruct::new(accounts.alice, min_delay, vec![accounts.alice], vec![]);
+        ink::env::test::set_account_balance::<DefaultEnvironment>(timelock.env().account_id(), 1000);
+        ink::env::test::set_account_balance::<DefaultEnvironment>(accounts.bob, 0);
+        change_caller(accounts.bob);
+        ink::env::test::set_value_transferred::<DefaultEnvironment>(100);
+
+        assert!(timelock_controller::Internal::_refund_unspent_value(&mut timelock, 30).is_ok());
+        assert_eq!(
+            ink::env::test::get_account_balance::<DefaultEnvironment>(accounts.bob),
+            Ok(70)
+        );
+        assert_eq!(
+            ink::env::test::get_account_balance::<DefaultEnvironment>(timelock.env().account_id()),
+            Ok(930)
+        );
+
+        assert!(timelock_controller::Internal::_refund_unspent_value(&mut timelock, 100).is_ok());
+        assert_eq!(
+            ink::env::test::get_account_balance::<DefaultEnvironment>(accounts.bob),
+            Ok(70)
+        );
+    }
 }
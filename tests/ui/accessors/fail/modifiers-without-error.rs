@@ -0,0 +1,14 @@
+use openbrush::{
+    contracts::ownable::*,
+    traits::Storage,
+};
+
+#[openbrush::accessors(ConfigAccessors: Storage<ownable::Data>)]
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct ConfigData {
+    #[set(modifiers(ownable::only_owner))]
+    fee: u32,
+}
+
+fn main() {}
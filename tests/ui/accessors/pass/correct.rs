@@ -1,4 +1,10 @@
-use openbrush::traits::Storage;
+use openbrush::{
+   storage::Mapping,
+   traits::{
+      AccountId,
+      Storage,
+   },
+};
 
 #[openbrush::accessors(AccessDataAccessors)]
 #[derive(Default, Debug)]
@@ -11,8 +17,15 @@ pub struct AccessData {
     read_only: u32,
     #[set]
     write_only: u32,
+    #[get]
+    #[set]
+    #[lazy]
+    lazy_read_write: Option<AccountId>,
+    #[get]
+    #[set]
+    config: Mapping<AccountId, u128>,
 }
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(AccessData);
 
-fn main() {}
\ No newline at end of file
+fn main() {}
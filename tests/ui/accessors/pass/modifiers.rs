@@ -0,0 +1,34 @@
+use openbrush::{
+    contracts::ownable::*,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+#[openbrush::accessors(ConfigAccessors: Storage<ownable::Data>, modifiers(ownable::only_owner), error = OwnableError)]
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct ConfigData {
+    #[get]
+    #[set]
+    fee: u32,
+    #[get]
+    #[set]
+    #[lazy]
+    treasury: Option<AccountId>,
+    #[get]
+    #[set]
+    limits: Mapping<AccountId, u128>,
+}
+
+#[openbrush::accessors(PublicAccessors)]
+#[derive(Default, Debug)]
+#[openbrush::storage_item]
+pub struct PublicData {
+    #[set(error = OwnableError)]
+    value: u32,
+}
+
+fn main() {}
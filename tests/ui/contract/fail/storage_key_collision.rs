@@ -0,0 +1,34 @@
+#[openbrush::contract]
+mod collision {
+    use openbrush::traits::Storage;
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item]
+    pub struct Data<T: scale::Encode + scale::Decode + ink::storage::traits::StorageLayout + scale_info::TypeInfo + 'static> {
+        #[lazy]
+        pub value: Option<T>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        small: Data<u8>,
+        #[storage_field]
+        big: Data<u128>,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> Option<u128> {
+            self.big.value.get_or_default()
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+#[openbrush::contract]
+mod collision {
+    use ink::storage::{
+        traits::ManualKey,
+        Lazy,
+    };
+    use openbrush::traits::Storage;
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item]
+    pub struct Data {
+        #[lazy]
+        pub value: Option<u128>,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        data: Data,
+        value: Lazy<u128, ManualKey<STORAGE_KEY_DATA_VALUE>>,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> Option<u128> {
+            self.value.get()
+        }
+    }
+}
+
+fn main() {}
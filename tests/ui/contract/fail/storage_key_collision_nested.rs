@@ -0,0 +1,40 @@
+#[openbrush::contract]
+mod collision {
+    use openbrush::traits::Storage;
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item]
+    pub struct Data {
+        #[lazy]
+        pub value: Option<u128>,
+    }
+
+    #[derive(Default, Debug)]
+    #[openbrush::storage_item]
+    pub struct Outer {
+        pub inner: Data,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct Contract {
+        #[storage_field]
+        data: Data,
+        #[storage_field]
+        outer: Outer,
+    }
+
+    impl Contract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn get(&self) -> Option<u128> {
+            self.outer.inner.value.get_or_default()
+        }
+    }
+}
+
+fn main() {}
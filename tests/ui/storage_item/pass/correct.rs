@@ -1,4 +1,12 @@
-use openbrush::traits::AccountId;
+use openbrush::{
+   storage::{
+      IterableMapping,
+      StorageDeque,
+      StorageSet,
+      StorageVec,
+   },
+   traits::AccountId,
+};
 #[derive(Debug)]
 #[openbrush::storage_item]
 pub struct OwnableData {
@@ -12,4 +20,18 @@ pub struct ProxyData {
    pub forward: AccountId,
 }
 
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct HoldersData {
+   pub holders: IterableMapping<AccountId, u128>,
+}
+
+#[derive(Debug)]
+#[openbrush::storage_item]
+pub struct CollectionsData {
+   pub payees: StorageVec<AccountId>,
+   pub members: StorageSet<AccountId>,
+   pub queue: StorageDeque<u128>,
+}
+
 fn main() {}
\ No newline at end of file